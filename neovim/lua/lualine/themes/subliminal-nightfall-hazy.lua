@@ -0,0 +1,19 @@
+-- Generated by colorloom
+local c = require('subliminal-nightfall.palette')['hazy']
+return {
+  normal = {
+    a = { fg = c.bg, bg = c.blue, gui = 'bold' },
+    b = { fg = c.fg, bg = c.bg_elevated },
+    c = { fg = c.fg_muted, bg = c.bg_alt },
+  },
+  insert = { a = { fg = c.bg, bg = c.green, gui = 'bold' } },
+  visual = { a = { fg = c.bg, bg = c.magenta, gui = 'bold' } },
+  replace = { a = { fg = c.bg, bg = c.red, gui = 'bold' } },
+  command = { a = { fg = c.bg, bg = c.yellow, gui = 'bold' } },
+  terminal = { a = { fg = c.bg, bg = c.cyan, gui = 'bold' } },
+  inactive = {
+    a = { fg = c.fg_dim, bg = c.bg_alt },
+    b = { fg = c.fg_dim, bg = c.bg_alt },
+    c = { fg = c.fg_dim, bg = c.bg_alt },
+  },
+}
@@ -0,0 +1,285 @@
+-- Generated by colorloom
+local M = {}
+
+-- Highlight groups styled by each [syntax] role in theme.toml.
+M.roles = {
+  ['comment'] = { 'Comment', '@comment', '@lsp.type.comment' },
+  ['keyword'] = { 'Keyword', 'Statement', 'Conditional', 'Repeat', '@keyword', '@lsp.type.keyword' },
+  ['function'] = { 'Function', '@function', '@function.call', '@lsp.type.function' },
+  ['method'] = { '@function.method', '@function.method.call', '@lsp.type.method' },
+  ['type'] = { 'Type', '@type', '@lsp.type.type', '@lsp.type.class', '@lsp.type.struct', '@lsp.type.enum', '@lsp.type.interface', '@lsp.type.typeParameter' },
+  ['constructor'] = { '@constructor' },
+  ['string'] = { 'String', '@string', '@lsp.type.string' },
+  ['escape'] = { 'SpecialChar', '@string.escape' },
+  ['regex'] = { '@string.regexp', '@lsp.type.regexp' },
+  ['number'] = { 'Number', 'Float', '@number', '@lsp.type.number' },
+  ['boolean'] = { 'Boolean', '@boolean' },
+  ['constant'] = { 'Constant', '@constant', '@lsp.type.enumMember' },
+  ['operator'] = { 'Operator', '@operator', '@lsp.type.operator' },
+  ['attribute'] = { '@attribute', '@lsp.type.decorator' },
+  ['variable'] = { 'Identifier', '@variable', '@lsp.type.variable' },
+  ['parameter'] = { '@variable.parameter', '@lsp.type.parameter' },
+  ['property'] = { '@property', '@variable.member', '@lsp.type.property' },
+  ['macro'] = { 'Macro', 'PreProc', '@function.macro', '@lsp.type.macro' },
+  ['namespace'] = { '@module', '@lsp.type.namespace' },
+  ['punctuation'] = { 'Delimiter', '@punctuation' },
+  ['tag'] = { 'Tag', '@tag' },
+}
+
+--- Highlight definitions for palette entry `c`, keyed by group name.
+function M.get(c, opts)
+  local g = {}
+  local function hl(name, val) g[name] = val end
+
+  -- Editor
+  hl('Normal', { fg = c.fg, bg = c.bg })
+  hl('NormalNC', { fg = c.fg, bg = c.bg })
+  hl('NormalFloat', { fg = c.fg, bg = c.bg_elevated })
+  hl('FloatBorder', { fg = c.border, bg = c.bg_elevated })
+  hl('FloatTitle', { fg = c.fg, bg = c.bg_elevated, bold = true })
+  hl('Cursor', { fg = c.cursor_fg, bg = c.cursor })
+  hl('lCursor', { link = 'Cursor' })
+  hl('CursorIM', { link = 'Cursor' })
+  hl('TermCursor', { link = 'Cursor' })
+  hl('CursorLine', { bg = c.line })
+  hl('CursorColumn', { bg = c.line })
+  hl('ColorColumn', { bg = c.bg_alt })
+  hl('CursorLineNr', { fg = c.fg, bold = true })
+  hl('LineNr', { fg = c.fg_dim })
+  hl('LineNrAbove', { link = 'LineNr' })
+  hl('LineNrBelow', { link = 'LineNr' })
+  hl('SignColumn', { fg = c.fg_dim, bg = c.bg })
+  hl('FoldColumn', { fg = c.fg_dim, bg = c.bg })
+  hl('Folded', { fg = c.fg_muted, bg = c.bg_alt })
+  hl('WinSeparator', { fg = c.border })
+  hl('VertSplit', { link = 'WinSeparator' })
+  hl('EndOfBuffer', { fg = c.bg })
+  hl('NonText', { fg = c.fg_dim })
+  hl('Whitespace', { fg = c.border_variant })
+  hl('SpecialKey', { fg = c.fg_dim })
+  hl('Conceal', { fg = c.fg_dim })
+  hl('Visual', { bg = c.selection, fg = c.selection_fg })
+  hl('VisualNOS', { link = 'Visual' })
+  hl('Search', { fg = c.bg, bg = c.yellow })
+  hl('IncSearch', { fg = c.bg, bg = c.cyan })
+  hl('CurSearch', { link = 'IncSearch' })
+  hl('Substitute', { fg = c.bg, bg = c.red })
+  hl('MatchParen', { fg = c.cyan, bold = true, underline = true })
+  hl('QuickFixLine', { bg = c.selection })
+  hl('Directory', { fg = c.blue })
+  hl('Title', { fg = c.blue, bold = true })
+  hl('Question', { fg = c.green })
+  hl('MoreMsg', { fg = c.green })
+  hl('ModeMsg', { fg = c.fg, bold = true })
+  hl('MsgArea', { fg = c.fg })
+  hl('ErrorMsg', { fg = c.error })
+  hl('WarningMsg', { fg = c.warn })
+  hl('SpellBad', { sp = c.error, undercurl = true })
+  hl('SpellCap', { sp = c.warn, undercurl = true })
+  hl('SpellLocal', { sp = c.info, undercurl = true })
+  hl('SpellRare', { sp = c.magenta, undercurl = true })
+
+  -- Status, tab and window bars
+  hl('StatusLine', { fg = c.fg, bg = c.bg_elevated })
+  hl('StatusLineNC', { fg = c.fg_dim, bg = c.bg_alt })
+  hl('TabLine', { fg = c.fg_muted, bg = c.bg_alt })
+  hl('TabLineFill', { bg = c.bg_alt })
+  hl('TabLineSel', { fg = c.fg, bg = c.bg, bold = true })
+  hl('WinBar', { fg = c.fg_muted, bg = c.bg })
+  hl('WinBarNC', { fg = c.fg_dim, bg = c.bg })
+
+  -- Popup menu
+  hl('Pmenu', { fg = c.fg, bg = c.bg_elevated })
+  hl('PmenuSel', { fg = c.selection_fg, bg = c.selection })
+  hl('PmenuKind', { fg = c.teal, bg = c.bg_elevated })
+  hl('PmenuKindSel', { fg = c.teal, bg = c.selection })
+  hl('PmenuExtra', { fg = c.fg_muted, bg = c.bg_elevated })
+  hl('PmenuExtraSel', { fg = c.fg_muted, bg = c.selection })
+  hl('PmenuSbar', { bg = c.bg_alt })
+  hl('PmenuThumb', { bg = c.border })
+  hl('WildMenu', { link = 'PmenuSel' })
+
+  -- Diff
+  hl('DiffAdd', { bg = c.diff_add })
+  hl('DiffChange', { bg = c.diff_change })
+  hl('DiffDelete', { fg = c.red, bg = c.diff_delete })
+  hl('DiffText', { bg = c.diff_text })
+  hl('Added', { fg = c.green })
+  hl('Changed', { fg = c.blue })
+  hl('Removed', { fg = c.red })
+
+  -- Diagnostics
+  hl('DiagnosticError', { fg = c.error })
+  hl('DiagnosticWarn', { fg = c.warn })
+  hl('DiagnosticInfo', { fg = c.info })
+  hl('DiagnosticHint', { fg = c.hint })
+  hl('DiagnosticOk', { fg = c.ok })
+  hl('DiagnosticUnderlineError', { sp = c.error, undercurl = true })
+  hl('DiagnosticUnderlineWarn', { sp = c.warn, undercurl = true })
+  hl('DiagnosticUnderlineInfo', { sp = c.info, undercurl = true })
+  hl('DiagnosticUnderlineHint', { sp = c.hint, undercurl = true })
+  hl('DiagnosticUnderlineOk', { sp = c.ok, undercurl = true })
+  hl('DiagnosticVirtualTextError', { fg = c.error, bg = c.error_bg })
+  hl('DiagnosticVirtualTextWarn', { fg = c.warn, bg = c.warn_bg })
+  hl('DiagnosticVirtualTextInfo', { fg = c.info, bg = c.info_bg })
+  hl('DiagnosticVirtualTextHint', { fg = c.hint, bg = c.hint_bg })
+  hl('DiagnosticVirtualTextOk', { fg = c.ok, bg = c.ok_bg })
+  hl('DiagnosticSignError', { fg = c.error })
+  hl('DiagnosticSignWarn', { fg = c.warn })
+  hl('DiagnosticSignInfo', { fg = c.info })
+  hl('DiagnosticSignHint', { fg = c.hint })
+  hl('DiagnosticSignOk', { fg = c.ok })
+  hl('DiagnosticFloatingError', { link = 'DiagnosticError' })
+  hl('DiagnosticFloatingWarn', { link = 'DiagnosticWarn' })
+  hl('DiagnosticFloatingInfo', { link = 'DiagnosticInfo' })
+  hl('DiagnosticFloatingHint', { link = 'DiagnosticHint' })
+  hl('DiagnosticFloatingOk', { link = 'DiagnosticOk' })
+  hl('DiagnosticUnnecessary', { fg = c.fg_dim })
+  hl('DiagnosticDeprecated', { strikethrough = true })
+
+  -- LSP
+  hl('LspReferenceText', { bg = c.line })
+  hl('LspReferenceRead', { bg = c.line })
+  hl('LspReferenceWrite', { bg = c.line, underline = true })
+  hl('LspInlayHint', { fg = c.fg_dim, bg = c.bg_alt, italic = true })
+  hl('LspCodeLens', { fg = c.fg_dim })
+  hl('LspSignatureActiveParameter', { bold = true, underline = true })
+
+  if opts.transparent then
+    hl('Normal', { fg = c.fg })
+    hl('NormalNC', { fg = c.fg })
+    hl('SignColumn', { fg = c.fg_dim })
+    hl('FoldColumn', { fg = c.fg_dim })
+  end
+
+  -- Syntax
+  for role, names in pairs(M.roles) do
+    if c.syntax[role] or opts.styles[role] then
+      for _, name in ipairs(names) do
+        hl(name, vim.tbl_extend('force', c.syntax[role] or {}, opts.styles[role] or {}))
+      end
+    end
+  end
+  hl('Special', { fg = c.cyan })
+  hl('Error', { fg = c.error })
+  hl('Todo', { fg = c.bg, bg = c.yellow, bold = true })
+  hl('Underlined', { underline = true })
+  hl('@variable.builtin', { link = '@keyword' })
+  hl('@label', { link = '@keyword' })
+  hl('@tag.attribute', { link = '@attribute' })
+  hl('@tag.delimiter', { link = '@punctuation' })
+  hl('@string.special.url', { fg = c.cyan, underline = true })
+  hl('@comment.error', { fg = c.error, bold = true })
+  hl('@comment.warning', { fg = c.warn, bold = true })
+  hl('@comment.todo', { link = 'Todo' })
+  hl('@comment.note', { fg = c.info, bold = true })
+  hl('@markup.heading', { fg = c.blue, bold = true })
+  hl('@markup.strong', { bold = true })
+  hl('@markup.italic', { italic = true })
+  hl('@markup.strikethrough', { strikethrough = true })
+  hl('@markup.underline', { underline = true })
+  hl('@markup.quote', { fg = c.fg_muted, italic = true })
+  hl('@markup.math', { fg = c.purple })
+  hl('@markup.link', { fg = c.cyan })
+  hl('@markup.link.url', { fg = c.cyan, underline = true })
+  hl('@markup.raw', { link = '@string' })
+  hl('@markup.list', { fg = c.cyan })
+  hl('@diff.plus', { fg = c.green })
+  hl('@diff.minus', { fg = c.red })
+  hl('@diff.delta', { fg = c.blue })
+
+  -- Plugin: telescope
+  if opts.plugins['telescope'] ~= false then
+    hl('TelescopeNormal', { fg = c.fg, bg = c.bg_alt })
+    hl('TelescopeBorder', { fg = c.border, bg = c.bg_alt })
+    hl('TelescopeTitle', { fg = c.bg, bg = c.blue, bold = true })
+    hl('TelescopePromptNormal', { fg = c.fg, bg = c.bg_elevated })
+    hl('TelescopePromptBorder', { fg = c.border, bg = c.bg_elevated })
+    hl('TelescopePromptTitle', { fg = c.bg, bg = c.cyan, bold = true })
+    hl('TelescopePromptPrefix', { fg = c.cyan, bg = c.bg_elevated })
+    hl('TelescopePreviewTitle', { fg = c.bg, bg = c.green, bold = true })
+    hl('TelescopeResultsTitle', { fg = c.bg, bg = c.blue, bold = true })
+    hl('TelescopeSelection', { fg = c.selection_fg, bg = c.selection })
+    hl('TelescopeSelectionCaret', { fg = c.cyan, bg = c.selection })
+    hl('TelescopeMultiSelection', { fg = c.magenta })
+    hl('TelescopeMatching', { fg = c.yellow, bold = true })
+  end
+
+  -- Plugin: gitsigns
+  if opts.plugins['gitsigns'] ~= false then
+    hl('GitSignsAdd', { fg = c.green })
+    hl('GitSignsChange', { fg = c.blue })
+    hl('GitSignsDelete', { fg = c.red })
+    hl('GitSignsAddNr', { link = 'GitSignsAdd' })
+    hl('GitSignsChangeNr', { link = 'GitSignsChange' })
+    hl('GitSignsDeleteNr', { link = 'GitSignsDelete' })
+    hl('GitSignsAddLn', { bg = c.diff_add })
+    hl('GitSignsChangeLn', { bg = c.diff_change })
+    hl('GitSignsDeleteLn', { bg = c.diff_delete })
+    hl('GitSignsAddInline', { bg = c.diff_add })
+    hl('GitSignsChangeInline', { bg = c.diff_text })
+    hl('GitSignsDeleteInline', { bg = c.diff_delete })
+    hl('GitSignsCurrentLineBlame', { fg = c.fg_dim, italic = true })
+  end
+
+  -- Plugin: nvim-cmp
+  if opts.plugins['nvim-cmp'] ~= false then
+    hl('CmpItemAbbr', { fg = c.fg })
+    hl('CmpItemAbbrDeprecated', { fg = c.fg_dim, strikethrough = true })
+    hl('CmpItemAbbrMatch', { fg = c.cyan, bold = true })
+    hl('CmpItemAbbrMatchFuzzy', { fg = c.cyan })
+    hl('CmpItemMenu', { fg = c.fg_muted })
+    hl('CmpItemKind', { fg = c.teal })
+    hl('CmpItemKindText', { fg = c.fg_muted })
+    hl('CmpItemKindSnippet', { fg = c.purple })
+    hl('CmpItemKindKeyword', { link = '@keyword' })
+    hl('CmpItemKindFunction', { link = '@function' })
+    hl('CmpItemKindMethod', { link = '@function.method' })
+    hl('CmpItemKindConstructor', { link = '@constructor' })
+    hl('CmpItemKindVariable', { link = '@variable' })
+    hl('CmpItemKindField', { link = '@property' })
+    hl('CmpItemKindProperty', { link = '@property' })
+    hl('CmpItemKindClass', { link = '@type' })
+    hl('CmpItemKindStruct', { link = '@type' })
+    hl('CmpItemKindInterface', { link = '@type' })
+    hl('CmpItemKindEnum', { link = '@type' })
+    hl('CmpItemKindEnumMember', { link = '@constant' })
+    hl('CmpItemKindConstant', { link = '@constant' })
+    hl('CmpItemKindModule', { link = '@module' })
+    hl('CmpItemKindOperator', { link = '@operator' })
+  end
+
+  -- Plugin: which-key
+  if opts.plugins['which-key'] ~= false then
+    hl('WhichKey', { fg = c.cyan })
+    hl('WhichKeyGroup', { fg = c.blue })
+    hl('WhichKeyDesc', { fg = c.fg })
+    hl('WhichKeySeparator', { fg = c.fg_dim })
+    hl('WhichKeyValue', { fg = c.fg_muted })
+    hl('WhichKeyNormal', { link = 'NormalFloat' })
+    hl('WhichKeyBorder', { link = 'FloatBorder' })
+  end
+
+  -- Plugin: nvim-tree
+  if opts.plugins['nvim-tree'] ~= false then
+    hl('NvimTreeNormal', { fg = c.fg, bg = c.bg_alt })
+    hl('NvimTreeNormalNC', { link = 'NvimTreeNormal' })
+    hl('NvimTreeWinSeparator', { fg = c.border, bg = c.bg_alt })
+    hl('NvimTreeCursorLine', { bg = c.line })
+    hl('NvimTreeRootFolder', { fg = c.blue, bold = true })
+    hl('NvimTreeFolderName', { fg = c.blue })
+    hl('NvimTreeOpenedFolderName', { fg = c.blue, bold = true })
+    hl('NvimTreeEmptyFolderName', { fg = c.fg_dim })
+    hl('NvimTreeFolderIcon', { fg = c.blue })
+    hl('NvimTreeIndentMarker', { fg = c.border })
+    hl('NvimTreeSpecialFile', { fg = c.magenta, underline = true })
+    hl('NvimTreeImageFile', { fg = c.purple })
+    hl('NvimTreeGitDirty', { fg = c.yellow })
+    hl('NvimTreeGitNew', { fg = c.green })
+    hl('NvimTreeGitDeleted', { fg = c.red })
+    hl('NvimTreeGitStaged', { fg = c.cyan })
+  end
+  return g
+end
+
+return M
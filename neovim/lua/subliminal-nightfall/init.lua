@@ -0,0 +1,47 @@
+-- Generated by colorloom
+local M = {}
+
+M.config = {
+  -- Palette to use when loading without an explicit variant.
+  variant = 'base',
+  -- Clear the editor background; nil follows the variant (translucent variants are transparent).
+  transparent = nil,
+  -- Per-role overrides merged into the theme's styles, e.g. { comment = { italic = false } }.
+  styles = {},
+  -- Set an integration to false to skip its groups, e.g. { telescope = false }.
+  plugins = {},
+  -- function(groups, colors): edit the highlight table before it is applied.
+  on_highlights = nil,
+}
+
+function M.setup(opts)
+  M.config = vim.tbl_deep_extend('force', M.config, opts or {})
+end
+
+--- Apply `variant`, or `M.config.variant` when omitted, as the current colorscheme.
+function M.load(variant)
+  local opts = vim.deepcopy(M.config)
+  opts.variant = variant or opts.variant
+  local c = require('subliminal-nightfall.palette')[opts.variant]
+  if not c then
+    error('Subliminal Nightfall: unknown variant ' .. tostring(opts.variant))
+  end
+  if opts.transparent == nil then opts.transparent = vim.g.subliminal_nightfall_transparent end
+  if opts.transparent == nil then opts.transparent = c.transparent end
+
+  local groups = require('subliminal-nightfall.groups').get(c, opts)
+  if opts.on_highlights then opts.on_highlights(groups, c) end
+
+  vim.cmd('highlight clear')
+  if vim.fn.exists('syntax_on') == 1 then vim.cmd('syntax reset') end
+  vim.o.background = 'dark'
+  vim.g.colors_name = c.name
+  for name, val in pairs(groups) do
+    vim.api.nvim_set_hl(0, name, val)
+  end
+  for i, color in ipairs(c.terminal) do
+    vim.g['terminal_color_' .. (i - 1)] = color
+  end
+end
+
+return M
@@ -0,0 +1,249 @@
+-- Generated by colorloom
+return {
+  ['base'] = {
+    name = 'subliminal-nightfall',
+    transparent = false,
+    bg = '#191724',
+    bg_alt = '#1f1d2e',
+    bg_elevated = '#26233a',
+    fg = '#e0def4',
+    fg_muted = '#a0a0a0',
+    fg_dim = '#7f7f7f',
+    selection = '#484e5b',
+    selection_fg = '#ffffff',
+    cursor = '#5fb3b3',
+    cursor_fg = '#ffffff',
+    line = '#2e3239',
+    border = '#484e5b',
+    border_variant = '#363b45',
+    border_focused = '#6699cc',
+    red = '#bf616a',
+    green = '#a9cfa4',
+    yellow = '#ffe2a9',
+    blue = '#6699cc',
+    magenta = '#f1a5ab',
+    cyan = '#5fb3b3',
+    purple = '#c4a7e7',
+    teal = '#9ccfd8',
+    dark_blue = '#31748f',
+    error = '#bf616a',
+    warn = '#ffe2a9',
+    info = '#6699cc',
+    hint = '#5fb3b3',
+    ok = '#a9cfa4',
+    error_bg = '#2a1f2b',
+    warn_bg = '#302c32',
+    info_bg = '#212435',
+    hint_bg = '#202733',
+    ok_bg = '#282a31',
+    diff_add = '#2e3237',
+    diff_change = '#242a3d',
+    diff_delete = '#32222e',
+    diff_text = '#303e57',
+    syntax = {
+      ['comment'] = { fg = '#7f7f7f', italic = true },
+      ['keyword'] = { fg = '#31748f' },
+      ['function'] = { fg = '#9ccfd8' },
+      ['method'] = { fg = '#9ccfd8' },
+      ['type'] = { fg = '#31748f' },
+      ['constructor'] = { fg = '#31748f' },
+      ['string'] = { fg = '#9ccfd8' },
+      ['escape'] = { fg = '#c4a7e7' },
+      ['regex'] = { fg = '#9ccfd8' },
+      ['number'] = { fg = '#c4a7e7' },
+      ['boolean'] = { fg = '#c4a7e7' },
+      ['constant'] = { fg = '#c4a7e7' },
+      ['operator'] = { fg = '#5fb3b3' },
+      ['attribute'] = { fg = '#f1a5ab', italic = true },
+      ['variable'] = { fg = '#e0def4' },
+      ['parameter'] = { fg = '#e0def4' },
+      ['property'] = { fg = '#e0def4' },
+      ['macro'] = { fg = '#9ccfd8' },
+      ['namespace'] = { fg = '#31748f' },
+      ['punctuation'] = { fg = '#a0a0a0' },
+      ['tag'] = { fg = '#31748f' },
+    },
+    terminal = {
+      '#7f7f7f',
+      '#bf616a',
+      '#a9cfa4',
+      '#ffe2a9',
+      '#6699cc',
+      '#f1a5ab',
+      '#5fb3b3',
+      '#d4d4d4',
+      '#7f7f7f',
+      '#e2848d',
+      '#ccf2c7',
+      '#ffffcc',
+      '#89bcef',
+      '#ffc8ce',
+      '#82d6d6',
+      '#ffffff',
+    },
+  },
+  ['blurred'] = {
+    name = 'subliminal-nightfall-blurred',
+    transparent = true,
+    bg = '#191725',
+    bg_alt = '#191725',
+    bg_elevated = '#1e1c2e',
+    fg = '#e0def4',
+    fg_muted = '#a0a0a0',
+    fg_dim = '#7f7f7f',
+    selection = '#3f4350',
+    selection_fg = '#ffffff',
+    cursor = '#5fb3b3',
+    cursor_fg = '#ffffff',
+    line = '#2a2d35',
+    border = '#484e5b',
+    border_variant = '#363b45',
+    border_focused = '#6699cc',
+    red = '#bf616a',
+    green = '#a9cfa4',
+    yellow = '#ffe2a9',
+    blue = '#6699cc',
+    magenta = '#f1a5ab',
+    cyan = '#5fb3b3',
+    purple = '#c4a7e7',
+    teal = '#9ccfd8',
+    dark_blue = '#31748f',
+    error = '#bf616a',
+    warn = '#ffe2a9',
+    info = '#6699cc',
+    hint = '#5fb3b3',
+    ok = '#a9cfa4',
+    error_bg = '#2a1f2c',
+    warn_bg = '#302c32',
+    info_bg = '#212436',
+    hint_bg = '#202733',
+    ok_bg = '#282a32',
+    diff_add = '#2e3238',
+    diff_change = '#242a3e',
+    diff_delete = '#32222f',
+    diff_text = '#303e57',
+    syntax = {
+      ['comment'] = { fg = '#7f7f7f', italic = true },
+      ['keyword'] = { fg = '#31748f' },
+      ['function'] = { fg = '#9ccfd8' },
+      ['method'] = { fg = '#9ccfd8' },
+      ['type'] = { fg = '#31748f' },
+      ['constructor'] = { fg = '#31748f' },
+      ['string'] = { fg = '#9ccfd8' },
+      ['escape'] = { fg = '#c4a7e7' },
+      ['regex'] = { fg = '#9ccfd8' },
+      ['number'] = { fg = '#c4a7e7' },
+      ['boolean'] = { fg = '#c4a7e7' },
+      ['constant'] = { fg = '#c4a7e7' },
+      ['operator'] = { fg = '#5fb3b3' },
+      ['attribute'] = { fg = '#f1a5ab', italic = true },
+      ['variable'] = { fg = '#e0def4' },
+      ['parameter'] = { fg = '#e0def4' },
+      ['property'] = { fg = '#e0def4' },
+      ['macro'] = { fg = '#9ccfd8' },
+      ['namespace'] = { fg = '#31748f' },
+      ['punctuation'] = { fg = '#a0a0a0' },
+      ['tag'] = { fg = '#31748f' },
+    },
+    terminal = {
+      '#7f7f7f',
+      '#bf616a',
+      '#a9cfa4',
+      '#ffe2a9',
+      '#6699cc',
+      '#f1a5ab',
+      '#5fb3b3',
+      '#d4d4d4',
+      '#7f7f7f',
+      '#e2848d',
+      '#ccf2c7',
+      '#ffffcc',
+      '#89bcef',
+      '#ffc8ce',
+      '#82d6d6',
+      '#ffffff',
+    },
+  },
+  ['hazy'] = {
+    name = 'subliminal-nightfall-hazy',
+    transparent = true,
+    bg = '#1a1927',
+    bg_alt = '#1a1927',
+    bg_elevated = '#201e31',
+    fg = '#e0def4',
+    fg_muted = '#a0a0a0',
+    fg_dim = '#7f7f7f',
+    selection = '#414653',
+    selection_fg = '#ffffff',
+    cursor = '#5fb3b3',
+    cursor_fg = '#ffffff',
+    line = '#2b2e36',
+    border = '#484e5b',
+    border_variant = '#363b45',
+    border_focused = '#6699cc',
+    red = '#bf616a',
+    green = '#a9cfa4',
+    yellow = '#ffe2a9',
+    blue = '#6699cc',
+    magenta = '#f1a5ab',
+    cyan = '#5fb3b3',
+    purple = '#c4a7e7',
+    teal = '#9ccfd8',
+    dark_blue = '#31748f',
+    error = '#bf616a',
+    warn = '#ffe2a9',
+    info = '#6699cc',
+    hint = '#5fb3b3',
+    ok = '#a9cfa4',
+    error_bg = '#2b202e',
+    warn_bg = '#312d34',
+    info_bg = '#222638',
+    hint_bg = '#212935',
+    ok_bg = '#292c34',
+    diff_add = '#2f343a',
+    diff_change = '#252c40',
+    diff_delete = '#332431',
+    diff_text = '#314059',
+    syntax = {
+      ['comment'] = { fg = '#7f7f7f', italic = true },
+      ['keyword'] = { fg = '#31748f' },
+      ['function'] = { fg = '#9ccfd8' },
+      ['method'] = { fg = '#9ccfd8' },
+      ['type'] = { fg = '#31748f' },
+      ['constructor'] = { fg = '#31748f' },
+      ['string'] = { fg = '#9ccfd8' },
+      ['escape'] = { fg = '#c4a7e7' },
+      ['regex'] = { fg = '#9ccfd8' },
+      ['number'] = { fg = '#c4a7e7' },
+      ['boolean'] = { fg = '#c4a7e7' },
+      ['constant'] = { fg = '#c4a7e7' },
+      ['operator'] = { fg = '#5fb3b3' },
+      ['attribute'] = { fg = '#f1a5ab', italic = true },
+      ['variable'] = { fg = '#e0def4' },
+      ['parameter'] = { fg = '#e0def4' },
+      ['property'] = { fg = '#e0def4' },
+      ['macro'] = { fg = '#9ccfd8' },
+      ['namespace'] = { fg = '#31748f' },
+      ['punctuation'] = { fg = '#a0a0a0' },
+      ['tag'] = { fg = '#31748f' },
+    },
+    terminal = {
+      '#7f7f7f',
+      '#bf616a',
+      '#a9cfa4',
+      '#ffe2a9',
+      '#6699cc',
+      '#f1a5ab',
+      '#5fb3b3',
+      '#d4d4d4',
+      '#7f7f7f',
+      '#e2848d',
+      '#ccf2c7',
+      '#ffffcc',
+      '#89bcef',
+      '#ffc8ce',
+      '#82d6d6',
+      '#ffffff',
+    },
+  },
+}
@@ -0,0 +1,72 @@
+//! Drift detection between rendered targets and the files on disk.
+
+use crate::targets::Output;
+use similar::TextDiff;
+use std::{fs, path::Path};
+
+/// A generated file whose on-disk contents differ from a fresh render.
+#[derive(Debug, Clone)]
+pub struct Drift {
+    pub output: Output,
+    /// Current contents, or `None` if the file is missing.
+    pub on_disk: Option<String>,
+}
+
+impl Drift {
+    /// Unified diff from the file on disk to the freshly rendered contents.
+    pub fn unified_diff(&self) -> String {
+        let path = self.output.path.display().to_string();
+        let old = self.on_disk.as_deref().unwrap_or("");
+        let old_header = if self.on_disk.is_some() { format!("a/{}", path) } else { "/dev/null".to_string() };
+        TextDiff::from_lines(old, &self.output.contents)
+            .unified_diff()
+            .context_radius(3)
+            .header(&old_header, &format!("b/{}", path))
+            .to_string()
+    }
+}
+
+/// Compare each rendered output with the file at the same path under `root`.
+pub fn compare(root: &Path, outputs: &[Output]) -> Vec<Drift> {
+    outputs
+        .iter()
+        .filter_map(|out| {
+            let on_disk = fs::read_to_string(root.join(&out.path)).ok();
+            if on_disk.as_deref() == Some(out.contents.as_str()) {
+                return None;
+            }
+            Some(Drift { output: out.clone(), on_disk })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_reports_modified_and_missing_files() {
+        let root = std::env::temp_dir().join(format!("colorloom-check-{}", std::process::id()));
+        fs::create_dir_all(root.join("themes")).unwrap();
+        fs::write(root.join("themes/current.conf"), "fg = #ffffff\nbg = #000000\n").unwrap();
+        fs::write(root.join("themes/stale.conf"), "fg = #ffffff\nbg = #000000\n").unwrap();
+        let outputs = [
+            Output::new("themes/current.conf", "fg = #ffffff\nbg = #000000\n"),
+            Output::new("themes/stale.conf", "fg = #ffffff\nbg = #191724\n"),
+            Output::new("themes/missing.conf", "fg = #ffffff\n"),
+        ];
+        let drift = compare(&root, &outputs);
+        fs::remove_dir_all(&root).unwrap();
+
+        let paths: Vec<_> = drift.iter().map(|d| d.output.path.to_str().unwrap()).collect();
+        assert_eq!(paths, ["themes/stale.conf", "themes/missing.conf"]);
+        assert_eq!(
+            drift[0].unified_diff(),
+            "--- a/themes/stale.conf\n+++ b/themes/stale.conf\n@@ -1,2 +1,2 @@\n fg = #ffffff\n-bg = #000000\n+bg = #191724\n"
+        );
+        assert_eq!(drift[1].on_disk, None);
+        let missing = drift[1].unified_diff();
+        assert!(missing.starts_with("--- /dev/null\n+++ b/themes/missing.conf\n"), "{}", missing);
+        assert!(missing.contains("+fg = #ffffff\n"), "{}", missing);
+    }
+}
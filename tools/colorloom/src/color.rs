@@ -0,0 +1,231 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// An sRGB color with an 8-bit alpha channel.
+///
+/// Parsed from `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`. Fully opaque colors
+/// are written back as `#rrggbb`, translucent ones as `#rrggbbaa`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color { r, g, b, a }
+    }
+
+    pub fn is_opaque(self) -> bool {
+        self.a == 255
+    }
+
+    /// Drop the alpha channel, keeping the RGB components as-is.
+    pub fn strip_alpha(self) -> Self {
+        Color { a: 255, ..self }
+    }
+
+    /// Replace the alpha channel with `a` (clamped to `0.0..=1.0`).
+    pub fn apply_alpha(self, a: f32) -> Self {
+        let a = a.clamp(0.0, 1.0);
+        Color { a: (a * 255.0).round() as u8, ..self }
+    }
+
+    /// Hue in degrees (`0.0..360.0`), saturation and lightness in `0.0..=1.0`.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (r, g, b) = (self.r as f32 / 255.0, self.g as f32 / 255.0, self.b as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        if d == 0.0 {
+            return (0.0, 0.0, l);
+        }
+        let s = d / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            60.0 * ((g - b) / d).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / d + 2.0)
+        } else {
+            60.0 * ((r - g) / d + 4.0)
+        };
+        (h, s, l)
+    }
+
+    /// Inverse of [`Color::to_hsl`], with an explicit alpha byte.
+    pub fn from_hsl(h: f32, s: f32, l: f32, a: u8) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let hp = h.rem_euclid(360.0) / 60.0;
+        let x = c * (1.0 - (hp % 2.0 - 1.0).abs());
+        let (r, g, b) = match hp as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        let to_u8 = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Color::rgba(to_u8(r), to_u8(g), to_u8(b), a)
+    }
+
+    /// Raise HSL lightness by `amount` (`0.0..=1.0`), keeping hue, saturation and alpha.
+    pub fn lighten(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, s, l + amount, self.a)
+    }
+
+    /// Lower HSL lightness by `amount` (`0.0..=1.0`), keeping hue, saturation and alpha.
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Composite `self` over `backdrop` (source-over). The result is opaque
+    /// whenever `backdrop` is.
+    pub fn over(self, backdrop: Color) -> Self {
+        let sa = self.a as f32 / 255.0;
+        let ba = backdrop.a as f32 / 255.0;
+        let oa = sa + ba * (1.0 - sa);
+        if oa == 0.0 {
+            return Color::rgba(0, 0, 0, 0);
+        }
+        let mix = |s: u8, b: u8| ((s as f32 * sa + b as f32 * ba * (1.0 - sa)) / oa).round() as u8;
+        Color::rgba(mix(self.r, backdrop.r), mix(self.g, backdrop.g), mix(self.b, backdrop.b), (oa * 255.0).round() as u8)
+    }
+
+    /// WCAG 2 relative luminance of the RGB components (alpha ignored).
+    pub fn relative_luminance(self) -> f64 {
+        let lin = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        0.2126 * lin(self.r) + 0.7152 * lin(self.g) + 0.0722 * lin(self.b)
+    }
+
+    /// WCAG 2 contrast ratio between two opaque colors, `1.0..=21.0`.
+    pub fn contrast_ratio(self, other: Color) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        let (hi, lo) = if a > b { (a, b) } else { (b, a) };
+        (hi + 0.05) / (lo + 0.05)
+    }
+
+    /// APCA (0.0.98G-4g) lightness contrast `Lc` of `self` as text on `background`.
+    /// Positive for dark text on light backgrounds, negative for light on dark.
+    pub fn apca_contrast(self, background: Color) -> f64 {
+        fn screen_y(c: Color) -> f64 {
+            let lin = |v: u8| (v as f64 / 255.0).powf(2.4);
+            let y = 0.2126729 * lin(c.r) + 0.7151522 * lin(c.g) + 0.0721750 * lin(c.b);
+            // Soft clamp near black.
+            if y < 0.022 { y + (0.022 - y).powf(1.414) } else { y }
+        }
+        let (txt, bg) = (screen_y(self), screen_y(background));
+        if (bg - txt).abs() < 0.0005 {
+            return 0.0;
+        }
+        let lc = if bg > txt {
+            let sapc = (bg.powf(0.56) - txt.powf(0.57)) * 1.14;
+            if sapc < 0.1 { 0.0 } else { sapc - 0.027 }
+        } else {
+            let sapc = (bg.powf(0.65) - txt.powf(0.62)) * 1.14;
+            if sapc > -0.1 { 0.0 } else { sapc + 0.027 }
+        };
+        lc * 100.0
+    }
+
+    /// `#rrggbb`, regardless of alpha.
+    pub fn to_hex_rgb(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// `#rrggbbaa`, always including alpha.
+    pub fn to_hex_rgba(self) -> String {
+        format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError(String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid color {:?}: expected #rgb, #rgba, #rrggbb or #rrggbbaa", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseColorError(s.to_string());
+        let hex = s.strip_prefix('#').ok_or_else(err)?;
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(err());
+        }
+        let nibble = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).map(|n| n * 17).map_err(|_| err());
+        let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| err());
+        match hex.len() {
+            3 => Ok(Color::rgb(nibble(0)?, nibble(1)?, nibble(2)?)),
+            4 => Ok(Color::rgba(nibble(0)?, nibble(1)?, nibble(2)?, nibble(3)?)),
+            6 => Ok(Color::rgb(byte(0)?, byte(2)?, byte(4)?)),
+            8 => Ok(Color::rgba(byte(0)?, byte(2)?, byte(4)?, byte(6)?)),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_opaque() { f.write_str(&self.to_hex_rgb()) } else { f.write_str(&self.to_hex_rgba()) }
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex_forms() {
+        assert_eq!("#abc".parse::<Color>().unwrap(), Color::rgb(0xaa, 0xbb, 0xcc));
+        assert_eq!("#abc8".parse::<Color>().unwrap(), Color::rgba(0xaa, 0xbb, 0xcc, 0x88));
+        assert_eq!("#191724".parse::<Color>().unwrap(), Color::rgb(0x19, 0x17, 0x24));
+        assert_eq!("#2e3239BF".parse::<Color>().unwrap(), Color::rgba(0x2e, 0x32, 0x39, 0xbf));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for s in ["#19172", "purple", "191724", "", "#", "#19172g", "#1917é", "#ééé"] {
+            assert!(s.parse::<Color>().is_err(), "{:?} should not parse", s);
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for s in ["#191724", "#2e3239bf"] {
+            assert_eq!(s.parse::<Color>().unwrap().to_string(), s);
+        }
+    }
+}
@@ -1,4 +1,8 @@
+use crate::color::Color;
+use crate::syntax::{Role, Style, SyntaxRoles};
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -7,11 +11,19 @@ pub struct Config {
     pub palette: Palette,
     pub variants: Vec<Variant>,
     pub targets: Vec<Target>,
+    /// Semantic syntax roles; see [`crate::syntax`].
+    #[serde(default)]
+    pub syntax: SyntaxRoles,
+    #[serde(default)]
+    pub contrast: ContrastThresholds,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Meta {
     pub name: String,
+    /// Release version, written into `cursor/package.json` and `extension.toml`.
+    #[serde(default)]
+    pub version: Option<String>,
     pub author: Option<String>,
     pub description: Option<String>,
     pub license: Option<String>,
@@ -25,6 +37,39 @@ pub struct Palette {
     pub border: BorderPalette,
 }
 
+impl Palette {
+    /// Every section resolved to opaque colors over `backdrop`, for formats
+    /// without alpha: `ui` as in [`UiPalette::flatten`], and ANSI, syntax and
+    /// border colors onto the flattened background they are drawn on.
+    pub fn flatten(&self, backdrop: Color) -> Palette {
+        let ui = self.ui.flatten(backdrop);
+        let bg = ui.background;
+        let (a, s, b) = (&self.base.ansi, &self.syntax, &self.border);
+        Palette {
+            base: BasePalette {
+                ansi: AnsiPalette {
+                    black: a.black.over(bg),
+                    red: a.red.over(bg),
+                    green: a.green.over(bg),
+                    yellow: a.yellow.over(bg),
+                    blue: a.blue.over(bg),
+                    magenta: a.magenta.over(bg),
+                    cyan: a.cyan.over(bg),
+                    white: a.white.over(bg),
+                },
+            },
+            syntax: SyntaxPalette { teal: s.teal.over(bg), blue_green: s.blue_green.over(bg), lavender: s.lavender.over(bg), gray: s.gray.over(bg) },
+            border: BorderPalette {
+                border: b.border.over(bg),
+                border_variant: b.border_variant.over(bg),
+                border_focused: b.border_focused.over(bg),
+                border_selected: b.border_selected.over(bg),
+            },
+            ui,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BasePalette {
     pub ansi: AnsiPalette,
@@ -32,57 +77,112 @@ pub struct BasePalette {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnsiPalette {
+    #[serde(default = "default_ansi_black")]
+    pub black: ColorVariant,
     pub red: ColorVariant,
     pub green: ColorVariant,
     pub yellow: ColorVariant,
     pub blue: ColorVariant,
     pub magenta: ColorVariant,
     pub cyan: ColorVariant,
+    #[serde(default = "default_ansi_white")]
+    pub white: ColorVariant,
+}
+
+impl AnsiPalette {
+    /// Named colors in ANSI order: `colors()[i].1.base` is palette slot `i`,
+    /// `.bright` is slot `i + 8`.
+    pub fn colors(&self) -> [(&'static str, &ColorVariant); 8] {
+        [
+            ("black", &self.black),
+            ("red", &self.red),
+            ("green", &self.green),
+            ("yellow", &self.yellow),
+            ("blue", &self.blue),
+            ("magenta", &self.magenta),
+            ("cyan", &self.cyan),
+            ("white", &self.white),
+        ]
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColorVariant {
-    pub base: String,
-    pub bright: String,
-    pub dim: String,
+    pub base: Color,
+    pub bright: Color,
+    pub dim: Color,
+}
+
+impl ColorVariant {
+    /// All three shades composited over `background`.
+    pub fn over(&self, background: Color) -> ColorVariant {
+        ColorVariant { base: self.base.over(background), bright: self.bright.over(background), dim: self.dim.over(background) }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyntaxPalette {
-    pub teal: String,
+    pub teal: Color,
     #[serde(rename = "blue_green")]
-    pub blue_green: String,
-    pub lavender: String,
-    pub gray: String,
+    pub blue_green: Color,
+    pub lavender: Color,
+    pub gray: Color,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UiPalette {
-    pub background: String,
+    pub background: Color,
     #[serde(rename = "background_alt")]
-    pub background_alt: String,
+    pub background_alt: Color,
     #[serde(rename = "background_elevated")]
-    pub background_elevated: String,
-    pub foreground: String,
+    pub background_elevated: Color,
+    pub foreground: Color,
     #[serde(rename = "foreground_muted")]
-    pub foreground_muted: String,
+    pub foreground_muted: Color,
     #[serde(rename = "foreground_dim")]
-    pub foreground_dim: String,
-    pub selection: String,
-    pub cursor: String,
+    pub foreground_dim: Color,
+    pub selection: Color,
+    pub cursor: Color,
+    /// Text under a block cursor.
+    #[serde(default = "default_white")]
+    pub cursor_text: Color,
+    #[serde(default = "default_white")]
+    pub selection_foreground: Color,
     #[serde(rename = "line_highlight")]
-    pub line_highlight: String,
+    pub line_highlight: Color,
+}
+
+impl UiPalette {
+    /// Resolve translucent colors to opaque ones as they would appear over
+    /// `backdrop`: surfaces composite onto the backdrop, and overlays
+    /// (selection, line highlight) and text onto the resulting background.
+    pub fn flatten(&self, backdrop: Color) -> UiPalette {
+        let background = self.background.over(backdrop);
+        UiPalette {
+            background,
+            background_alt: self.background_alt.over(backdrop),
+            background_elevated: self.background_elevated.over(backdrop),
+            foreground: self.foreground.over(background),
+            foreground_muted: self.foreground_muted.over(background),
+            foreground_dim: self.foreground_dim.over(background),
+            selection: self.selection.over(background),
+            cursor: self.cursor.over(background),
+            cursor_text: self.cursor_text.over(background),
+            selection_foreground: self.selection_foreground.over(background),
+            line_highlight: self.line_highlight.over(background),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BorderPalette {
-    pub border: String,
+    pub border: Color,
     #[serde(rename = "border_variant")]
-    pub border_variant: String,
+    pub border_variant: Color,
     #[serde(rename = "border_focused")]
-    pub border_focused: String,
+    pub border_focused: Color,
     #[serde(rename = "border_selected")]
-    pub border_selected: String,
+    pub border_selected: Color,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -91,31 +191,170 @@ pub struct Variant {
     pub alpha: Option<f32>,
     #[serde(default)]
     pub blur_radius: Option<u32>,
+    /// What the window is expected to sit on. Targets that can't express
+    /// transparency composite translucent colors over this. Defaults to black.
+    #[serde(default)]
+    pub backdrop: Option<Color>,
     #[serde(default)]
     pub overrides: Option<Overrides>,
 }
 
+impl Variant {
+    pub fn backdrop(&self) -> Color {
+        self.backdrop.unwrap_or(Color::rgb(0, 0, 0))
+    }
+
+    /// `base` as this variant renders for the target with id `target`, in
+    /// increasing precedence: the variant's `alpha` on the background and
+    /// overlay colors, `[variants.overrides]`, then
+    /// `[variants.overrides.targets.<target>]`.
+    pub fn palette(&self, base: &Palette, target: Option<&str>) -> Palette {
+        let mut palette = base.clone();
+        if let Some(alpha) = self.alpha {
+            let ui = &mut palette.ui;
+            ui.background = ui.background.apply_alpha(alpha);
+            ui.background_alt = ui.background_alt.apply_alpha(alpha);
+            ui.background_elevated = ui.background_elevated.apply_alpha(alpha);
+            ui.selection = ui.selection.apply_alpha(alpha);
+            ui.line_highlight = ui.line_highlight.apply_alpha(alpha);
+        }
+        if let Some(ov) = &self.overrides {
+            ov.palette.apply(&mut palette);
+            if let Some(o) = target.and_then(|t| ov.targets.get(t)) { o.apply(&mut palette); }
+        }
+        palette
+    }
+}
+
+/// Palette changes for one variant. The top-level sections apply to every
+/// target; `targets.<id>` sections are applied on top of them for the
+/// `[[targets]]` entry with that id only.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Overrides {
+    #[serde(flatten)]
+    pub palette: PaletteOverrides,
+    #[serde(default)]
+    pub targets: BTreeMap<String, PaletteOverrides>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PaletteOverrides {
     pub ui: Option<UiOverrides>,
+    pub ansi: Option<AnsiOverrides>,
+    pub syntax: Option<SyntaxOverrides>,
+    pub border: Option<BorderOverrides>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UiOverrides {
-    pub background: Option<String>,
-    pub background_alt: Option<String>,
-    pub background_elevated: Option<String>,
-    pub selection: Option<String>,
-    pub cursor: Option<String>,
-    pub line_highlight: Option<String>,
-    pub foreground: Option<String>,
-    pub foreground_muted: Option<String>,
-    pub foreground_dim: Option<String>,
+    pub background: Option<Color>,
+    pub background_alt: Option<Color>,
+    pub background_elevated: Option<Color>,
+    pub selection: Option<Color>,
+    pub cursor: Option<Color>,
+    pub cursor_text: Option<Color>,
+    pub selection_foreground: Option<Color>,
+    pub line_highlight: Option<Color>,
+    pub foreground: Option<Color>,
+    pub foreground_muted: Option<Color>,
+    pub foreground_dim: Option<Color>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AnsiOverrides {
+    pub black: Option<ColorVariantOverrides>,
+    pub red: Option<ColorVariantOverrides>,
+    pub green: Option<ColorVariantOverrides>,
+    pub yellow: Option<ColorVariantOverrides>,
+    pub blue: Option<ColorVariantOverrides>,
+    pub magenta: Option<ColorVariantOverrides>,
+    pub cyan: Option<ColorVariantOverrides>,
+    pub white: Option<ColorVariantOverrides>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ColorVariantOverrides {
+    pub base: Option<Color>,
+    pub bright: Option<Color>,
+    pub dim: Option<Color>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SyntaxOverrides {
+    pub teal: Option<Color>,
+    pub blue_green: Option<Color>,
+    pub lavender: Option<Color>,
+    pub gray: Option<Color>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BorderOverrides {
+    pub border: Option<Color>,
+    pub border_variant: Option<Color>,
+    pub border_focused: Option<Color>,
+    pub border_selected: Option<Color>,
+}
+
+impl PaletteOverrides {
+    /// Replace every color set here in `palette`.
+    pub fn apply(&self, palette: &mut Palette) {
+        if let Some(o) = &self.ui {
+            let ui = &mut palette.ui;
+            if let Some(v) = o.background { ui.background = v; }
+            if let Some(v) = o.background_alt { ui.background_alt = v; }
+            if let Some(v) = o.background_elevated { ui.background_elevated = v; }
+            if let Some(v) = o.selection { ui.selection = v; }
+            if let Some(v) = o.cursor { ui.cursor = v; }
+            if let Some(v) = o.cursor_text { ui.cursor_text = v; }
+            if let Some(v) = o.selection_foreground { ui.selection_foreground = v; }
+            if let Some(v) = o.line_highlight { ui.line_highlight = v; }
+            if let Some(v) = o.foreground { ui.foreground = v; }
+            if let Some(v) = o.foreground_muted { ui.foreground_muted = v; }
+            if let Some(v) = o.foreground_dim { ui.foreground_dim = v; }
+        }
+        if let Some(o) = &self.ansi {
+            let ansi = &mut palette.base.ansi;
+            let pairs = [
+                (&o.black, &mut ansi.black),
+                (&o.red, &mut ansi.red),
+                (&o.green, &mut ansi.green),
+                (&o.yellow, &mut ansi.yellow),
+                (&o.blue, &mut ansi.blue),
+                (&o.magenta, &mut ansi.magenta),
+                (&o.cyan, &mut ansi.cyan),
+                (&o.white, &mut ansi.white),
+            ];
+            for (o, c) in pairs {
+                let Some(o) = o else { continue };
+                if let Some(v) = o.base { c.base = v; }
+                if let Some(v) = o.bright { c.bright = v; }
+                if let Some(v) = o.dim { c.dim = v; }
+            }
+        }
+        if let Some(o) = &self.syntax {
+            let syntax = &mut palette.syntax;
+            if let Some(v) = o.teal { syntax.teal = v; }
+            if let Some(v) = o.blue_green { syntax.blue_green = v; }
+            if let Some(v) = o.lavender { syntax.lavender = v; }
+            if let Some(v) = o.gray { syntax.gray = v; }
+        }
+        if let Some(o) = &self.border {
+            let border = &mut palette.border;
+            if let Some(v) = o.border { border.border = v; }
+            if let Some(v) = o.border_variant { border.border_variant = v; }
+            if let Some(v) = o.border_focused { border.border_focused = v; }
+            if let Some(v) = o.border_selected { border.border_selected = v; }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Target {
     pub id: String,
+    /// Generator to use; defaults to `id`. Lets several entries share one generator,
+    /// e.g. multiple `kind = "template"` targets.
+    #[serde(default)]
+    pub kind: Option<String>,
     #[serde(default = "default_true")]
     pub enabled: bool,
     pub path: String,
@@ -123,12 +362,145 @@ pub struct Target {
     pub out_file: Option<String>,
     #[serde(default)]
     pub out_names: Option<std::collections::HashMap<String, String>>, // variant -> filename
+    /// Template file for `kind = "template"` targets, relative to the working directory.
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Extension manifest, relative to the working directory: `package.json` for
+    /// the `cursor` target, `extension.toml` for `zed`. Its metadata (and for
+    /// `cursor`, the theme list) is kept in sync with the config.
+    #[serde(default)]
+    pub manifest: Option<String>,
+    /// Plugin integrations for the `neovim` target; every supported plugin when unset.
+    #[serde(default)]
+    pub plugins: Option<Vec<String>>,
+}
+
+/// Minimum contrast for `colorloom validate`. `text` thresholds apply to body
+/// text, syntax and ANSI colors; `muted` ones to comments and muted/dim UI text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContrastThresholds {
+    /// WCAG 2 contrast ratio (AA body text is 4.5).
+    pub text: f64,
+    pub muted: f64,
+    /// Absolute APCA `Lc` value.
+    pub apca_text: f64,
+    pub apca_muted: f64,
+}
+
+impl Default for ContrastThresholds {
+    fn default() -> Self {
+        ContrastThresholds { text: 4.5, muted: 3.0, apca_text: 45.0, apca_muted: 30.0 }
+    }
 }
 
 fn default_true() -> bool { true }
 
+fn default_white() -> Color { Color::rgb(0xff, 0xff, 0xff) }
+
+// Defaults for configs written before black/white were part of the schema;
+// they reproduce the values generators used to hard-code.
+fn default_ansi_black() -> ColorVariant {
+    ColorVariant { base: Color::rgb(0x7f, 0x7f, 0x7f), bright: Color::rgb(0x7f, 0x7f, 0x7f), dim: Color::rgb(0x59, 0x59, 0x59) }
+}
+
+fn default_ansi_white() -> ColorVariant {
+    ColorVariant { base: Color::rgb(0xd4, 0xd4, 0xd4), bright: Color::rgb(0xff, 0xff, 0xff), dim: Color::rgb(0x94, 0x94, 0x94) }
+}
+
+impl Meta {
+    /// Lowercase, dash-separated name used for default output file names.
+    pub fn slug(&self) -> String {
+        self.name.to_lowercase().replace(' ', "-")
+    }
+}
+
+impl Target {
+    /// Registry id of the generator that renders this entry.
+    pub fn kind(&self) -> &str {
+        self.kind.as_deref().unwrap_or(&self.id)
+    }
+}
+
 impl Config {
+    /// Read and parse a theme TOML file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let cfg: Config = toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))?;
+        cfg.syntax.check(&cfg.palette).with_context(|| format!("parsing {}", path.display()))?;
+        for v in &cfg.variants {
+            for id in v.overrides.iter().flat_map(|o| o.targets.keys()) {
+                if !cfg.targets.iter().any(|t| &t.id == id) {
+                    bail!("parsing {}: variant `{}` overrides unknown target `{}`", path.display(), v.name, id);
+                }
+            }
+        }
+        Ok(cfg)
+    }
+
+    /// Resolved style for a syntax role.
+    pub fn syntax_style(&self, role: Role) -> Style {
+        self.syntax.style(&self.palette, role)
+    }
+
     pub fn variant_names(&self) -> Vec<String> {
         self.variants.iter().map(|v| v.name.clone()).collect()
     }
 }
+
+/// Which component of a `major.minor.patch` version to increment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionPart {
+    Major,
+    Minor,
+    Patch,
+}
+
+/// Increment `[meta].version` in the theme TOML at `path` and return the new
+/// version. The file is edited in place, so comments and layout survive.
+pub fn bump_version(path: &Path, part: VersionPart) -> Result<String> {
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let (text, version) = bump_version_in(&text, part).with_context(|| format!("bumping the version in {}", path.display()))?;
+    fs::write(path, text).with_context(|| format!("writing {}", path.display()))?;
+    Ok(version)
+}
+
+fn bump_version_in(text: &str, part: VersionPart) -> Result<(String, String)> {
+    let mut doc: toml_edit::DocumentMut = text.parse()?;
+    let item = doc.get_mut("meta").and_then(|meta| meta.get_mut("version")).context("`[meta].version` is not set")?;
+    let current = item.as_str().context("`[meta].version` is not a string")?;
+    let numbers: Vec<u64> = current.split('.').map(str::parse).collect::<Result<_, _>>().unwrap_or_default();
+    let [major, minor, patch] = numbers[..] else { bail!("`[meta].version` {:?} is not major.minor.patch", current) };
+    let next = match part {
+        VersionPart::Major => format!("{}.0.0", major + 1),
+        VersionPart::Minor => format!("{}.{}.0", major, minor + 1),
+        VersionPart::Patch => format!("{}.{}.{}", major, minor, patch + 1),
+    };
+    // Assign through the existing value so its surrounding whitespace and comment stay put.
+    let decor = item.as_value().map(|v| v.decor().clone()).unwrap_or_default();
+    *item = toml_edit::value(next.as_str());
+    if let Some(v) = item.as_value_mut() { *v.decor_mut() = decor; }
+    Ok((doc.to_string(), next))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THEME: &str = "version = \"1\"\n\n# Release metadata\n[meta]\nname = \"Theme\"\nversion = \"0.1.9\" # bumped in CI\n\n[palette]\nversion = \"9.9.9\"\n";
+
+    #[test]
+    fn bump_version_edits_only_meta_version() {
+        let (text, version) = bump_version_in(THEME, VersionPart::Patch).unwrap();
+        assert_eq!(version, "0.1.10");
+        assert_eq!(text, THEME.replace("0.1.9", "0.1.10"));
+        assert_eq!(bump_version_in(THEME, VersionPart::Minor).unwrap().1, "0.2.0");
+        assert_eq!(bump_version_in(THEME, VersionPart::Major).unwrap().1, "1.0.0");
+    }
+
+    #[test]
+    fn bump_version_rejects_unversioned_configs() {
+        assert!(bump_version_in("[meta]\nname = \"Theme\"\n", VersionPart::Patch).is_err());
+        assert!(bump_version_in("[meta]\nversion = \"1.2\"\n", VersionPart::Patch).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! Contrast linting for the color pairings the generators emit.
+
+use crate::color::Color;
+use crate::config::{Config, ContrastThresholds, Variant};
+use crate::syntax::Role as SyntaxRole;
+use serde::Serialize;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Text,
+    Muted,
+}
+
+/// One foreground/background pairing, measured after alpha compositing.
+#[derive(Debug, Clone, Serialize)]
+pub struct PairReport {
+    pub variant: String,
+    pub foreground_key: String,
+    pub background_key: String,
+    pub foreground: Color,
+    pub background: Color,
+    pub role: Role,
+    /// WCAG 2 contrast ratio.
+    pub ratio: f64,
+    /// APCA `Lc`; negative for light text on a dark background.
+    pub apca: f64,
+    pub passes: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub thresholds: ContrastThresholds,
+    pub failures: usize,
+    pub pairs: Vec<PairReport>,
+}
+
+/// Measure every pairing for every variant against `cfg.contrast`.
+pub fn report(cfg: &Config) -> Report {
+    let thresholds = cfg.contrast.clone();
+    let pairs: Vec<_> = cfg.variants.iter().flat_map(|v| variant_pairs(cfg, v, &thresholds)).collect();
+    Report { failures: pairs.iter().filter(|p| !p.passes).count(), thresholds, pairs }
+}
+
+fn variant_pairs(cfg: &Config, variant: &Variant, th: &ContrastThresholds) -> Vec<PairReport> {
+    // Measure what the user sees: translucent colors over the variant backdrop.
+    // Per-target overrides aren't included; this checks the shared palette.
+    let palette = variant.palette(&cfg.palette, None);
+    let ui = palette.ui.flatten(variant.backdrop());
+    let (bg, bg_alt, selection) = (ui.background, ui.background_alt, ui.selection);
+
+    let mut pairs = Vec::new();
+    let mut add = |fg_key: &str, bg_key: &str, fg: Color, bg: Color, role: Role| {
+        pairs.push(measure(variant, fg_key, bg_key, fg, bg, role, th));
+    };
+    add("ui.foreground", "ui.background", ui.foreground, bg, Role::Text);
+    add("ui.foreground_muted", "ui.background_alt", ui.foreground_muted, bg_alt, Role::Muted);
+    add("ui.foreground_dim", "ui.background_alt", ui.foreground_dim, bg_alt, Role::Muted);
+    for (role, style) in cfg.syntax.styles(&palette) {
+        let role_kind = if role == SyntaxRole::Comment { Role::Muted } else { Role::Text };
+        if let Some(c) = style.color { add(&format!("syntax.{}", role.name()), "ui.background", c, bg, role_kind); }
+    }
+    // ANSI black is a background color on a dark theme, so it isn't measured as text.
+    for (name, c) in palette.base.ansi.colors().into_iter().filter(|(name, _)| *name != "black") {
+        add(&format!("ansi.{}.base", name), "ui.background", c.base, bg, Role::Text);
+        add(&format!("ansi.{}.bright", name), "ui.background", c.bright, bg, Role::Text);
+    }
+    add("ui.selection_foreground", "ui.selection", ui.selection_foreground, selection, Role::Text);
+    pairs
+}
+
+fn measure(variant: &Variant, fg_key: &str, bg_key: &str, fg: Color, bg: Color, role: Role, th: &ContrastThresholds) -> PairReport {
+    let fg = fg.over(bg);
+    let ratio = fg.contrast_ratio(bg);
+    let apca = fg.apca_contrast(bg);
+    let (min_ratio, min_apca) = match role {
+        Role::Text => (th.text, th.apca_text),
+        Role::Muted => (th.muted, th.apca_muted),
+    };
+    PairReport {
+        variant: variant.name.clone(),
+        foreground_key: fg_key.to_string(),
+        background_key: bg_key.to_string(),
+        foreground: fg,
+        background: bg,
+        role,
+        ratio: (ratio * 100.0).round() / 100.0,
+        apca: (apca * 10.0).round() / 10.0,
+        passes: ratio >= min_ratio && apca.abs() >= min_apca,
+    }
+}
+
+impl Report {
+    /// Human-readable table, one row per pairing.
+    pub fn table(&self) -> String {
+        let width = |key: fn(&PairReport) -> &str, header: &str| self.pairs.iter().map(|p| key(p).len()).chain([header.len()]).max().unwrap_or(0);
+        let variant_w = width(|p| &p.variant, "variant");
+        let fg_w = width(|p| &p.foreground_key, "foreground");
+        let bg_w = width(|p| &p.background_key, "background");
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:<variant_w$} {:<fg_w$} {:<bg_w$} {:<8} {:<8} {:<6} {:>6} {:>7}  status",
+            "variant", "foreground", "background", "fg", "bg", "role", "ratio", "APCA"
+        );
+        for p in &self.pairs {
+            let role = match p.role {
+                Role::Text => "text",
+                Role::Muted => "muted",
+            };
+            let _ = writeln!(
+                out,
+                "{:<variant_w$} {:<fg_w$} {:<bg_w$} {:<8} {:<8} {:<6} {:>6.2} {:>7.1}  {}",
+                p.variant,
+                p.foreground_key,
+                p.background_key,
+                p.foreground.to_string(),
+                p.background.to_string(),
+                role,
+                p.ratio,
+                p.apca,
+                if p.passes { "ok" } else { "LOW" }
+            );
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn foreground_pair(report: &Report) -> &PairReport {
+        report.pairs.iter().find(|p| p.variant == "base" && p.foreground_key == "ui.foreground").unwrap()
+    }
+
+    #[test]
+    fn low_contrast_is_judged_by_configured_thresholds() {
+        let mut cfg = crate::targets::test_config();
+        cfg.palette.ui.foreground = Color::rgb(0x3a, 0x37, 0x4a);
+        let report = report(&cfg);
+        let pair = foreground_pair(&report);
+        assert!(!pair.passes && pair.ratio < cfg.contrast.text, "{:?}", pair);
+        assert!(report.failures > 0);
+        let row = report.table().lines().find(|l| l.starts_with("base") && l.contains("ui.foreground ")).unwrap().to_string();
+        assert!(row.ends_with("LOW"), "{}", row);
+
+        cfg.contrast.text = 1.0;
+        cfg.contrast.apca_text = 0.0;
+        assert!(foreground_pair(&super::report(&cfg)).passes);
+    }
+
+    #[test]
+    fn json_report_shape() {
+        let cfg = crate::targets::test_config();
+        let json = serde_json::to_value(report(&cfg)).unwrap();
+        let keys = |v: &Value| v.as_object().unwrap().keys().cloned().collect::<Vec<_>>();
+        assert_eq!(keys(&json), ["failures", "pairs", "thresholds"]);
+        assert_eq!(keys(&json["thresholds"]), ["apca_muted", "apca_text", "muted", "text"]);
+        let pairs = json["pairs"].as_array().unwrap();
+        assert_eq!(json["failures"], pairs.iter().filter(|p| p["passes"] == false).count());
+        for pair in pairs {
+            assert_eq!(keys(pair), ["apca", "background", "background_key", "foreground", "foreground_key", "passes", "ratio", "role", "variant"]);
+            assert!(pair["foreground"].as_str().is_some_and(|c| c.len() == 7 && c.starts_with('#')), "{}", pair);
+            assert!(pair["ratio"].is_f64() && pair["apca"].is_f64(), "{}", pair);
+            assert!(matches!(pair["role"].as_str(), Some("text" | "muted")), "{}", pair);
+        }
+    }
+}
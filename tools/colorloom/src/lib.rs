@@ -0,0 +1,15 @@
+//! Theme generation for Subliminal Nightfall.
+//!
+//! `colorloom` reads a `theme.toml` into [`config::Config`] and renders it
+//! through the generators in a [`targets::Registry`]. Downstream crates can
+//! register their own [`targets::Target`] implementations alongside the
+//! built-in ones.
+
+pub mod check;
+pub mod color;
+pub mod config;
+pub mod contrast;
+pub mod schema;
+pub mod syntax;
+pub mod targets;
+pub mod watch;
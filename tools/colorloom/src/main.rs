@@ -1,9 +1,7 @@
-mod config;
-mod targets;
-
-use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
-use std::{fs, path::PathBuf};
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use colorloom::{check, config::{self, Config, VersionPart}, contrast, targets::Registry, watch};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(name = "colorloom", version, about = "Subliminal Nightfall theme generator")] 
@@ -18,36 +16,131 @@ struct Cli {
 
 #[derive(Subcommand, Debug)]
 enum Cmd {
-    /// Validate the configuration file
-    Validate,
+    /// Validate the configuration file and lint color contrast
+    Validate {
+        /// Contrast report format
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        /// Exit non-zero if any pairing is below the contrast thresholds
+        #[arg(long)]
+        strict: bool,
+    },
     /// Generate themes for all enabled targets
     Generate,
-    /// List targets and variants
+    /// List variants and available target generators
     List,
+    /// Render all enabled targets in memory and fail if any file on disk is stale
+    Check,
+    /// Regenerate affected targets whenever the config or a template changes
+    Watch,
+    /// Increment `[meta].version` in the config and print the new version
+    Bump {
+        /// Version component to increment
+        #[arg(value_enum, default_value_t = Part::Patch)]
+        part: Part,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Format {
+    Table,
+    Json,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Part {
+    Major,
+    Minor,
+    Patch,
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
     let root = std::env::current_dir()?;
-    let cfg_text = fs::read_to_string(&cli.config).with_context(|| format!("reading {}", cli.config.display()))?;
-    let cfg: config::Config = toml::from_str(&cfg_text).context("parsing theme.toml")?;
+    let registry = Registry::with_builtins();
+    if let Some(Cmd::Watch) = cli.cmd {
+        return watch::run(&cli.config, &root, &registry);
+    }
+    let cfg = Config::load(&cli.config)?;
 
     match cli.cmd.unwrap_or(Cmd::Generate) {
-        Cmd::Validate => {
-            println!("OK: {} variants, {} targets", cfg.variants.len(), cfg.targets.len());
+        Cmd::Validate { format, strict } => {
+            for t in &cfg.targets {
+                if registry.get(t.kind()).is_none() {
+                    bail!("Unknown target kind: {} (available: {})", t.kind(), registry.ids().join(", "));
+                }
+            }
+            let report = contrast::report(&cfg);
+            match format {
+                Format::Table => {
+                    println!("OK: {} variants, {} targets", cfg.variants.len(), cfg.targets.len());
+                    println!();
+                    print!("{}", report.table());
+                    println!();
+                    println!("{} of {} pairings below contrast thresholds", report.failures, report.pairs.len());
+                }
+                Format::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+            }
+            if strict && report.failures > 0 {
+                bail!("{} pairings below contrast thresholds", report.failures);
+            }
         }
         Cmd::List => {
             println!("Variants: {}", cfg.variant_names().join(", "));
-            for t in cfg.targets.iter().filter(|t| t.enabled) {
-                println!("- {} -> {}", t.id, t.path);
+            println!("Targets:");
+            for g in registry.iter() {
+                let configured: Vec<_> = cfg.targets.iter().filter(|t| t.kind() == g.id()).collect();
+                let status = match configured.as_slice() {
+                    [] => "not configured".to_string(),
+                    ts => ts
+                        .iter()
+                        .map(|t| {
+                            let label = if t.id == g.id() { t.path.clone() } else { format!("{} ({})", t.path, t.id) };
+                            format!("{}{}", label, if t.enabled { "" } else { " (disabled)" })
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                };
+                let caps = g.capabilities();
+                let mut flags = vec![match (caps.per_variant_files, caps.variants) {
+                    (true, _) => "per-variant files",
+                    (false, true) => "single file, all variants",
+                    (false, false) => "single file, base only",
+                }];
+                if caps.alpha { flags.push("alpha"); }
+                if caps.blur { flags.push("blur"); }
+                println!("- {}: {} [{}] -> {}", g.id(), g.description(), flags.join(", "), status);
             }
         }
         Cmd::Generate => {
             for t in cfg.targets.iter().filter(|t| t.enabled) {
-                targets::generate_target(&cfg, t, &root).with_context(|| format!("generating target {}", t.id))?;
+                registry.generate(&cfg, t, &root).with_context(|| format!("generating target {}", t.id))?;
             }
             println!("Generated themes for {} targets", cfg.targets.iter().filter(|t| t.enabled).count());
         }
+        Cmd::Check => {
+            let mut outputs = Vec::new();
+            for t in cfg.targets.iter().filter(|t| t.enabled) {
+                outputs.extend(registry.render(&cfg, t, &root).with_context(|| format!("rendering target {}", t.id))?);
+            }
+            let stale = check::compare(&root, &outputs);
+            for drift in &stale {
+                print!("{}", drift.unified_diff());
+            }
+            if !stale.is_empty() {
+                bail!("{} of {} generated files are out of date; run `mise run gen`", stale.len(), outputs.len());
+            }
+            println!("OK: {} generated files up to date", outputs.len());
+        }
+        Cmd::Bump { part } => {
+            let part = match part {
+                Part::Major => VersionPart::Major,
+                Part::Minor => VersionPart::Minor,
+                Part::Patch => VersionPart::Patch,
+            };
+            println!("{}", config::bump_version(&cli.config, part)?);
+        }
+        Cmd::Watch => unreachable!("handled before loading the config"),
     }
     Ok(())
 }
@@ -0,0 +1,229 @@
+//! Validation of generated theme documents against vendored JSON Schemas.
+//!
+//! Only the subset of JSON Schema the files in `schemas/` use is supported:
+//! `type`, `enum`, `pattern`, `required`, `properties`, `patternProperties`,
+//! `additionalProperties`, `items`, `anyOf`/`oneOf` and local `$ref`s.
+
+use anyhow::{bail, Result};
+use regex::Regex;
+use serde_json::Value;
+use std::{collections::HashMap, sync::LazyLock};
+
+/// Zed theme family JSON (`https://zed.dev/schema/themes/v0.2.0.json`).
+pub static ZED_THEME: LazyLock<Schema> =
+    LazyLock::new(|| Schema::parse("Zed theme v0.2.0", include_str!("../schemas/zed-theme-v0.2.0.json")));
+
+/// VS Code / Cursor color theme JSON.
+pub static VSCODE_THEME: LazyLock<Schema> =
+    LazyLock::new(|| Schema::parse("VS Code color theme", include_str!("../schemas/vscode-color-theme.json")));
+
+pub struct Schema {
+    name: &'static str,
+    root: Value,
+    /// Every `pattern` and `patternProperties` key, compiled once.
+    patterns: HashMap<String, Regex>,
+}
+
+impl Schema {
+    /// Parse a schema compiled into the binary; panics on malformed JSON or
+    /// an invalid regex.
+    pub fn parse(name: &'static str, text: &str) -> Self {
+        let root = serde_json::from_str(text).unwrap_or_else(|e| panic!("{} schema is not valid JSON: {}", name, e));
+        let mut patterns = HashMap::new();
+        collect_patterns(&root, &mut patterns).unwrap_or_else(|e| panic!("{} schema has an invalid pattern: {}", name, e));
+        Schema { name, root, patterns }
+    }
+
+    /// Every violation in `doc`, as `/json/pointer: message`.
+    pub fn errors(&self, doc: &Value) -> Vec<String> {
+        let mut errors = Vec::new();
+        self.walk(&self.root, doc, "", &mut errors);
+        errors
+    }
+
+    /// Fail, listing every violation, unless `doc` conforms.
+    pub fn validate(&self, doc: &Value) -> Result<()> {
+        let errors = self.errors(doc);
+        if errors.is_empty() {
+            return Ok(());
+        }
+        bail!("does not match the {} schema:\n  {}", self.name, errors.join("\n  "))
+    }
+
+    /// Follow local `$ref`s such as `#/definitions/Name`; the unresolvable
+    /// reference is returned as the error.
+    fn resolve<'a>(&'a self, mut schema: &'a Value) -> Result<&'a Value, &'a str> {
+        while let Some(r) = schema.get("$ref").and_then(Value::as_str) {
+            schema = r.strip_prefix('#').and_then(|pointer| self.root.pointer(pointer)).ok_or(r)?;
+        }
+        Ok(schema)
+    }
+
+    fn walk(&self, schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+        let at = if path.is_empty() { "/" } else { path };
+        let schema = match self.resolve(schema) {
+            Ok(schema) => schema,
+            Err(r) => return errors.push(format!("{}: schema reference `{}` does not resolve", at, r)),
+        };
+        let matches = |s: &Value| {
+            let mut errs = Vec::new();
+            self.walk(s, value, path, &mut errs);
+            errs.is_empty()
+        };
+        if let Some(alternatives) = schema.get("anyOf").and_then(Value::as_array) {
+            if !alternatives.iter().any(matches) {
+                errors.push(format!("{}: {} matches none of the allowed forms", at, value));
+            }
+        }
+        if let Some(alternatives) = schema.get("oneOf").and_then(Value::as_array) {
+            match alternatives.iter().filter(|s| matches(s)).count() {
+                1 => {}
+                0 => errors.push(format!("{}: {} matches none of the allowed forms", at, value)),
+                n => errors.push(format!("{}: {} matches {} of the allowed forms, expected exactly one", at, value, n)),
+            }
+        }
+        if let Some(types) = schema.get("type") {
+            let types: Vec<&str> = match types {
+                Value::Array(list) => list.iter().filter_map(Value::as_str).collect(),
+                other => other.as_str().into_iter().collect(),
+            };
+            if !types.iter().any(|t| type_matches(t, value)) {
+                errors.push(format!("{}: expected {}, found {}", at, types.join(" or "), type_name(value)));
+                return;
+            }
+        }
+        if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+            if !options.contains(value) {
+                let allowed: Vec<String> = options.iter().map(Value::to_string).collect();
+                errors.push(format!("{}: {} is not one of {}", at, value, allowed.join(", ")));
+            }
+        }
+        if let (Some(pattern), Some(s)) = (schema.get("pattern").and_then(Value::as_str), value.as_str()) {
+            if !self.matches_pattern(pattern, s) {
+                errors.push(format!("{}: {:?} does not match {}", at, s, pattern));
+            }
+        }
+        match value {
+            Value::Object(map) => {
+                for key in schema.get("required").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str) {
+                    if !map.contains_key(key) {
+                        errors.push(format!("{}: missing required key `{}`", at, key));
+                    }
+                }
+                let properties = schema.get("properties").and_then(Value::as_object);
+                let patterns = schema.get("patternProperties").and_then(Value::as_object);
+                for (key, child) in map {
+                    let child_path = format!("{}/{}", path, key);
+                    let mut known = false;
+                    if let Some(s) = properties.and_then(|p| p.get(key)) {
+                        known = true;
+                        self.walk(s, child, &child_path, errors);
+                    }
+                    for (pattern, s) in patterns.into_iter().flatten() {
+                        if self.matches_pattern(pattern, key) {
+                            known = true;
+                            self.walk(s, child, &child_path, errors);
+                        }
+                    }
+                    if known {
+                        continue;
+                    }
+                    match schema.get("additionalProperties") {
+                        Some(Value::Bool(false)) => errors.push(format!("{}: unknown key", child_path)),
+                        Some(s @ Value::Object(_)) => self.walk(s, child, &child_path, errors),
+                        _ => {}
+                    }
+                }
+            }
+            Value::Array(items) => {
+                if let Some(s) = schema.get("items") {
+                    for (i, item) in items.iter().enumerate() {
+                        self.walk(s, item, &format!("{}/{}", path, i), errors);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn matches_pattern(&self, pattern: &str, s: &str) -> bool {
+        self.patterns.get(pattern).is_some_and(|re| re.is_match(s))
+    }
+}
+
+/// Compile every regex in `schema` into `patterns`, keyed by its source.
+fn collect_patterns(schema: &Value, patterns: &mut HashMap<String, Regex>) -> Result<(), regex::Error> {
+    match schema {
+        Value::Object(map) => {
+            let sources = map.get("pattern").and_then(Value::as_str).into_iter();
+            let keys = map.get("patternProperties").and_then(Value::as_object).into_iter().flat_map(|p| p.keys().map(String::as_str));
+            for source in sources.chain(keys) {
+                if !patterns.contains_key(source) {
+                    patterns.insert(source.to_string(), Regex::new(source)?);
+                }
+            }
+            map.values().try_for_each(|v| collect_patterns(v, patterns))
+        }
+        Value::Array(items) => items.iter().try_for_each(|v| collect_patterns(v, patterns)),
+        _ => Ok(()),
+    }
+}
+
+fn type_matches(name: &str, value: &Value) -> bool {
+    match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn one_of_requires_exactly_one_match() {
+        let schema = Schema::parse("test", r#"{ "oneOf": [{ "type": "string" }, { "pattern": "^#" }] }"#);
+        assert!(schema.errors(&json!(1)).is_empty());
+        assert!(schema.errors(&json!("red")).is_empty());
+        assert_eq!(schema.errors(&json!("#fff")).len(), 1);
+    }
+
+    #[test]
+    fn unresolved_ref_is_an_error() {
+        let schema = Schema::parse("test", r##"{ "properties": { "a": { "$ref": "#/definitions/Missing" } } }"##);
+        let errors = schema.errors(&json!({ "a": 1 }));
+        assert_eq!(errors, ["/a: schema reference `#/definitions/Missing` does not resolve"]);
+    }
+
+    #[test]
+    fn pattern_properties_use_compiled_patterns() {
+        let schema = Schema::parse("test", r#"{ "patternProperties": { "^x-": { "type": "string" } }, "additionalProperties": false }"#);
+        assert!(schema.errors(&json!({ "x-a": "ok" })).is_empty());
+        assert_eq!(schema.errors(&json!({ "x-a": 1, "y": "" })).len(), 2);
+    }
+
+    #[test]
+    fn vscode_colors_reject_unknown_ids() {
+        let theme = |colors| json!({ "name": "t", "type": "dark", "colors": colors, "tokenColors": [] });
+        assert!(VSCODE_THEME.errors(&theme(json!({ "editor.background": "#191724" }))).is_empty());
+        assert_eq!(VSCODE_THEME.errors(&theme(json!({ "editor.backgroud": "#191724" }))), ["/colors/editor.backgroud: unknown key"]);
+    }
+}
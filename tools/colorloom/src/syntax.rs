@@ -0,0 +1,301 @@
+//! Semantic syntax roles: the shared vocabulary `[syntax]` assigns styles to,
+//! and that each generator translates into its own scope or group names.
+
+use crate::color::Color;
+use crate::config::Palette;
+use anyhow::{anyhow, Result};
+use serde::de::{self, value::MapAccessDeserializer, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Comment,
+    Keyword,
+    Function,
+    Method,
+    Type,
+    Constructor,
+    String,
+    Escape,
+    Regex,
+    Number,
+    Boolean,
+    Constant,
+    Operator,
+    Attribute,
+    Variable,
+    Parameter,
+    Property,
+    Macro,
+    Namespace,
+    Punctuation,
+    Tag,
+}
+
+impl Role {
+    pub const ALL: [Role; 21] = [
+        Role::Comment,
+        Role::Keyword,
+        Role::Function,
+        Role::Method,
+        Role::Type,
+        Role::Constructor,
+        Role::String,
+        Role::Escape,
+        Role::Regex,
+        Role::Number,
+        Role::Boolean,
+        Role::Constant,
+        Role::Operator,
+        Role::Attribute,
+        Role::Variable,
+        Role::Parameter,
+        Role::Property,
+        Role::Macro,
+        Role::Namespace,
+        Role::Punctuation,
+        Role::Tag,
+    ];
+
+    /// Key used in `[syntax]`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Role::Comment => "comment",
+            Role::Keyword => "keyword",
+            Role::Function => "function",
+            Role::Method => "method",
+            Role::Type => "type",
+            Role::Constructor => "constructor",
+            Role::String => "string",
+            Role::Escape => "escape",
+            Role::Regex => "regex",
+            Role::Number => "number",
+            Role::Boolean => "boolean",
+            Role::Constant => "constant",
+            Role::Operator => "operator",
+            Role::Attribute => "attribute",
+            Role::Variable => "variable",
+            Role::Parameter => "parameter",
+            Role::Property => "property",
+            Role::Macro => "macro",
+            Role::Namespace => "namespace",
+            Role::Punctuation => "punctuation",
+            Role::Tag => "tag",
+        }
+    }
+
+    /// Role whose style is used when this one isn't set in `[syntax]`.
+    pub fn parent(self) -> Option<Role> {
+        match self {
+            Role::Method | Role::Macro => Some(Role::Function),
+            Role::Constructor | Role::Namespace => Some(Role::Type),
+            Role::Escape | Role::Regex => Some(Role::String),
+            Role::Boolean => Some(Role::Constant),
+            Role::Parameter | Role::Property => Some(Role::Variable),
+            _ => None,
+        }
+    }
+}
+
+/// A `[syntax]` entry as written in the config. `color` is either a hex color
+/// or a palette reference such as `syntax.teal`, `ansi.cyan`,
+/// `ansi.cyan.bright` or `ui.foreground`. A bare string is shorthand for
+/// `{ color = "..." }`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StyleSpec {
+    pub color: Option<String>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+/// The table form of a [`StyleSpec`]; misspelled keys are errors rather than
+/// silently dropping the color.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FullStyleSpec {
+    color: Option<String>,
+    #[serde(default)]
+    bold: bool,
+    #[serde(default)]
+    italic: bool,
+    #[serde(default)]
+    underline: bool,
+}
+
+impl<'de> Deserialize<'de> for StyleSpec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SpecVisitor;
+
+        impl<'de> Visitor<'de> for SpecVisitor {
+            type Value = StyleSpec;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a color or a table of color, bold, italic and underline")
+            }
+
+            fn visit_str<E: de::Error>(self, color: &str) -> Result<StyleSpec, E> {
+                Ok(StyleSpec { color: Some(color.to_string()), ..Default::default() })
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<StyleSpec, A::Error> {
+                let FullStyleSpec { color, bold, italic, underline } = FullStyleSpec::deserialize(MapAccessDeserializer::new(map))?;
+                Ok(StyleSpec { color, bold, italic, underline })
+            }
+        }
+
+        deserializer.deserialize_any(SpecVisitor)
+    }
+}
+
+/// A role's style with its color resolved. `color` is `None` when the role
+/// should use the editor's default foreground.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Style {
+    pub color: Option<Color>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+/// The `[syntax]` section: role -> style.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SyntaxRoles(pub BTreeMap<Role, StyleSpec>);
+
+impl Default for SyntaxRoles {
+    /// The mapping generators hard-coded before `[syntax]` existed.
+    fn default() -> Self {
+        let spec = |color: &str, italic: bool| StyleSpec { color: Some(color.to_string()), italic, ..Default::default() };
+        SyntaxRoles(BTreeMap::from([
+            (Role::Comment, spec("syntax.gray", true)),
+            (Role::Keyword, spec("syntax.blue_green", false)),
+            (Role::Function, spec("syntax.teal", false)),
+            (Role::String, spec("syntax.teal", false)),
+            (Role::Number, spec("syntax.lavender", false)),
+            (Role::Constant, spec("syntax.lavender", false)),
+            (Role::Operator, spec("ansi.cyan", false)),
+            (Role::Attribute, spec("ansi.magenta", true)),
+        ]))
+    }
+}
+
+impl SyntaxRoles {
+    /// Style for `role`, falling back through [`Role::parent`]. Unresolvable
+    /// colors become `None`; [`SyntaxRoles::check`] reports them at load time.
+    pub fn style(&self, palette: &Palette, role: Role) -> Style {
+        self.resolve_style(&PaletteRefs::new(palette), role)
+    }
+
+    fn resolve_style(&self, refs: &PaletteRefs, role: Role) -> Style {
+        let mut current = Some(role);
+        while let Some(r) = current {
+            if let Some(spec) = self.0.get(&r) {
+                return Style {
+                    color: spec.color.as_deref().and_then(|c| refs.resolve(c).ok()),
+                    bold: spec.bold,
+                    italic: spec.italic,
+                    underline: spec.underline,
+                };
+            }
+            current = r.parent();
+        }
+        Style::default()
+    }
+
+    /// Every role in [`Role::ALL`] order with its resolved style.
+    pub fn styles(&self, palette: &Palette) -> Vec<(Role, Style)> {
+        let refs = PaletteRefs::new(palette);
+        Role::ALL.iter().map(|&r| (r, self.resolve_style(&refs, r))).collect()
+    }
+
+    /// Fail on colors that are neither hex nor a known palette reference.
+    pub fn check(&self, palette: &Palette) -> Result<()> {
+        let refs = PaletteRefs::new(palette);
+        for (role, spec) in &self.0 {
+            if let Some(c) = &spec.color {
+                refs.resolve(c).map_err(|e| anyhow!("syntax.{}: {}", role.name(), e))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Every color in a palette by the dotted path a `[syntax]` entry may use,
+/// built once per palette. `ansi.*` is shorthand for `base.ansi.*`, and a
+/// base/bright/dim triple's path also names its `.base`.
+struct PaletteRefs(HashMap<String, Color>);
+
+impl PaletteRefs {
+    fn new(palette: &Palette) -> Self {
+        let mut refs = HashMap::new();
+        if let Ok(tree) = serde_json::to_value(palette) {
+            collect_refs(String::new(), &tree, &mut refs);
+        }
+        let shorthands: Vec<_> = refs.iter().filter_map(|(k, c)| k.strip_prefix("base.").map(|k| (k.to_string(), *c))).collect();
+        refs.extend(shorthands);
+        PaletteRefs(refs)
+    }
+
+    /// Resolve a `[syntax]` color: `#hex`, or a palette path.
+    fn resolve(&self, reference: &str) -> Result<Color> {
+        if reference.starts_with('#') {
+            return reference.parse().map_err(|e| anyhow!("{}", e));
+        }
+        self.0.get(reference).copied().ok_or_else(|| anyhow!("unknown palette color `{}`", reference))
+    }
+}
+
+fn collect_refs(path: String, node: &Value, refs: &mut HashMap<String, Color>) {
+    match node {
+        Value::String(s) => {
+            if let Ok(c) = s.parse() { refs.insert(path, c); }
+        }
+        Value::Object(map) => {
+            if let Some(c) = map.get("base").and_then(Value::as_str).and_then(|s| s.parse().ok()) { refs.insert(path.clone(), c); }
+            for (key, child) in map {
+                let child_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                collect_refs(child_path, child, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::targets::test_config;
+
+    #[test]
+    fn style_spec_accepts_both_forms() {
+        let roles: SyntaxRoles = toml::from_str("comment = \"syntax.gray\"\nkeyword = { color = \"ansi.cyan.bright\", bold = true }\n").unwrap();
+        assert_eq!(roles.0[&Role::Comment].color.as_deref(), Some("syntax.gray"));
+        assert!(roles.0[&Role::Keyword].bold);
+    }
+
+    #[test]
+    fn style_spec_rejects_unknown_keys() {
+        let err = toml::from_str::<SyntaxRoles>("keyword = { colour = \"#ff0000\", bold = true }\n").unwrap_err();
+        assert!(err.to_string().contains("unknown field `colour`"), "{}", err);
+    }
+
+    #[test]
+    fn references_resolve_against_the_palette() {
+        let cfg = test_config();
+        let (p, refs) = (&cfg.palette, PaletteRefs::new(&cfg.palette));
+        assert_eq!(refs.resolve("syntax.teal").unwrap(), p.syntax.teal);
+        assert_eq!(refs.resolve("ansi.cyan").unwrap(), p.base.ansi.cyan.base);
+        assert_eq!(refs.resolve("base.ansi.cyan.bright").unwrap(), p.base.ansi.cyan.bright);
+        assert_eq!(refs.resolve("ui.foreground").unwrap(), p.ui.foreground);
+        assert_eq!(refs.resolve("#123").unwrap(), Color::rgb(0x11, 0x22, 0x33));
+        assert!(refs.resolve("ui.foregrond").is_err());
+        assert!(refs.resolve("base").is_err());
+    }
+}
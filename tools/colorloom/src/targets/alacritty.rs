@@ -0,0 +1,84 @@
+use super::{Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Variant};
+use anyhow::Result;
+use std::{fmt::Write, path::Path};
+
+pub struct Alacritty;
+
+impl Target for Alacritty {
+    fn id(&self) -> &'static str {
+        "alacritty"
+    }
+
+    fn description(&self) -> &'static str {
+        "Alacritty TOML color config"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: true,
+            variants: true,
+            alpha: false,
+            blur: true,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String {
+        let suffix = variant.map(|v| format!("-{}", v.name)).unwrap_or_default();
+        format!("{}{}.toml", cfg.meta.slug(), suffix)
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, _root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        let mut outputs = Vec::new();
+        for v in self.variants(cfg) {
+            let background = self.window_background(cfg, target, v);
+            let palette = self.palette(cfg, target, v);
+            let (ui, ansi) = (&palette.ui, &palette.base.ansi);
+            // Text drawn on accent-colored search matches and hints.
+            let on_accent = ui.background;
+            let mut out = String::new();
+            let _ = writeln!(out, "# {} for Alacritty", cfg.meta.name);
+            section(&mut out, "colors.primary", &[
+                ("background", background.strip_alpha()),
+                ("foreground", ui.foreground),
+                ("dim_foreground", ui.foreground_dim),
+                ("bright_foreground", ansi.white.bright),
+            ]);
+            section(&mut out, "colors.cursor", &[("text", ui.cursor_text), ("cursor", ui.cursor)]);
+            section(&mut out, "colors.vi_mode_cursor", &[("text", ui.cursor_text), ("cursor", ansi.magenta.base)]);
+            section(&mut out, "colors.selection", &[("text", ui.selection_foreground), ("background", ui.selection)]);
+            section(&mut out, "colors.search.matches", &[("foreground", on_accent), ("background", ansi.yellow.base)]);
+            section(&mut out, "colors.search.focused_match", &[("foreground", on_accent), ("background", ansi.cyan.base)]);
+            section(&mut out, "colors.hints.start", &[("foreground", on_accent), ("background", ansi.yellow.base)]);
+            section(&mut out, "colors.hints.end", &[("foreground", on_accent), ("background", ansi.yellow.dim)]);
+            section(&mut out, "colors.footer_bar", &[("foreground", ui.foreground), ("background", ui.background_alt)]);
+            section(&mut out, "colors.line_indicator", &[("foreground", ui.foreground_muted), ("background", ui.background_alt)]);
+            let colors = ansi.colors();
+            section(&mut out, "colors.normal", &colors.map(|(name, c)| (name, c.base)));
+            section(&mut out, "colors.bright", &colors.map(|(name, c)| (name, c.bright)));
+            section(&mut out, "colors.dim", &colors.map(|(name, c)| (name, c.dim)));
+            let blur = v.blur_radius.is_some_and(|r| r > 0);
+            if !background.is_opaque() || blur {
+                out.push_str("\n[window]\n");
+                if !background.is_opaque() {
+                    let _ = writeln!(out, "opacity = {:.2}", background.a as f32 / 255.0);
+                }
+                // Alacritty's blur is on/off; the compositor picks the radius.
+                if blur {
+                    out.push_str("blur = true\n");
+                }
+            }
+            outputs.push(Output::new(dir.join(self.file_name(cfg, target, Some(v))), out));
+        }
+        Ok(outputs)
+    }
+}
+
+fn section(out: &mut String, name: &str, entries: &[(&str, Color)]) {
+    let _ = writeln!(out, "\n[{}]", name);
+    for (key, color) in entries {
+        let _ = writeln!(out, "{} = \"{}\"", key, color);
+    }
+}
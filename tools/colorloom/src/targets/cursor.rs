@@ -0,0 +1,509 @@
+use super::{capitalize, variant_label, Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Palette, Variant};
+use crate::schema;
+use crate::syntax::{Role, Style};
+use anyhow::{bail, Context, Result};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub struct Cursor;
+
+impl Target for Cursor {
+    fn id(&self) -> &'static str {
+        "cursor"
+    }
+
+    fn description(&self) -> &'static str {
+        "VS Code / Cursor color theme JSON"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: true,
+            variants: true,
+            alpha: true,
+            blur: false,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String {
+        let suffix = variant.map(|v| format!("-{}", v.name)).unwrap_or_default();
+        format!("{}{}.json", cfg.meta.slug(), suffix)
+    }
+
+    fn inputs(&self, target: &config::Target) -> Vec<PathBuf> {
+        target.manifest.iter().map(PathBuf::from).collect()
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        let mut outputs = Vec::new();
+        let mut themes = Vec::new();
+        for v in self.variants(cfg) {
+            let palette = self.palette(cfg, target, v);
+            let name = self.file_name(cfg, target, Some(v));
+            let label = variant_label(cfg, v, " ({})");
+            themes.push((label.clone(), dir.join(&name)));
+            let mut theme = json!({
+                "name": label,
+                "type": "dark",
+                "semanticHighlighting": true,
+                "colors": workbench_colors(&palette).into_iter().map(|(k, c)| (k.to_string(), json!(c))).collect::<Map<_, _>>(),
+                "tokenColors": token_colors(cfg, &palette),
+                "semanticTokenColors": semantic_token_colors(cfg, &palette)
+            });
+            // VS Code has no dim ANSI slots; it renders SGR 2 (faint) text at
+            // reduced opacity of the normal color, so `dim` is not emitted here.
+            let colors = theme["colors"].as_object_mut().expect("colors is an object");
+            for (name, c) in palette.base.ansi.colors() {
+                colors.insert(format!("terminal.ansi{}", capitalize(name)), json!(c.base));
+                colors.insert(format!("terminal.ansiBright{}", capitalize(name)), json!(c.bright));
+            }
+            let path = dir.join(name);
+            schema::VSCODE_THEME.validate(&theme).with_context(|| format!("validating {}", path.display()))?;
+            outputs.push(Output::new(path, serde_json::to_string_pretty(&theme)?));
+        }
+        if let Some(manifest) = &target.manifest {
+            outputs.push(package_manifest(cfg, Path::new(manifest), root, &themes)?);
+        }
+        Ok(outputs)
+    }
+}
+
+/// The extension's `package.json` with `contributes.themes` listing every
+/// rendered variant, and version/description/author/license taken from
+/// `[meta]`. Other fields are preserved; a minimal manifest is created if the
+/// file doesn't exist yet.
+fn package_manifest(cfg: &Config, path: &Path, root: &Path, themes: &[(String, PathBuf)]) -> Result<Output> {
+    let mut pkg: Json = match fs::read_to_string(root.join(path)) {
+        Ok(text) => serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => object([
+            ("name", string(cfg.meta.slug())),
+            ("displayName", string(&cfg.meta.name)),
+            ("description", string("")),
+            ("version", string("0.0.1")),
+            ("engines", object([("vscode", string(">=1.70.0"))])),
+            ("categories", Json::Array(vec![string("Themes")])),
+        ]),
+        Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+    };
+    let Json::Object(obj) = &mut pkg else { bail!("{} is not a JSON object", path.display()) };
+    let meta = &cfg.meta;
+    for (key, value) in [("version", &meta.version), ("description", &meta.description), ("author", &meta.author), ("license", &meta.license)] {
+        if let Some(v) = value { obj.insert(key.to_string(), string(v)); }
+    }
+    let base = path.parent().unwrap_or(Path::new(""));
+    let entries: Vec<_> = themes
+        .iter()
+        .map(|(label, file)| {
+            let rel = file.strip_prefix(base).unwrap_or(file);
+            object([("label", string(label)), ("uiTheme", string("vs-dark")), ("path", string(format!("./{}", rel.display())))])
+        })
+        .collect();
+    let Json::Object(contributes) = obj.entry("contributes".to_string()).or_insert_with(|| object([])) else {
+        bail!("{}: `contributes` is not an object", path.display())
+    };
+    contributes.insert("themes".into(), Json::Array(entries));
+    Ok(Output::new(path, serde_json::to_string_pretty(&pkg)? + "\n"))
+}
+
+/// JSON that keeps object keys in file order, so rewriting package.json
+/// leaves the fields colorloom doesn't manage where they were.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(serde_json::Number),
+    String(String),
+    Array(Vec<Json>),
+    Object(IndexMap<String, Json>),
+}
+
+fn object<const N: usize>(entries: [(&str, Json); N]) -> Json {
+    Json::Object(entries.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+}
+
+fn string(s: impl ToString) -> Json {
+    Json::String(s.to_string())
+}
+
+/// Workbench `colors`, derived from the variant's UI palette, borders and
+/// ANSI accents. Translucent tints are used for overlays (diff, find
+/// matches, merge conflicts) so the text underneath keeps its syntax colors.
+fn workbench_colors(palette: &Palette) -> Vec<(&'static str, Color)> {
+    let (ui, b, a) = (&palette.ui, &palette.border, &palette.base.ansi);
+    let (red, green, yellow, blue, magenta, cyan) = (a.red.base, a.green.base, a.yellow.base, a.blue.base, a.magenta.base, a.cyan.base);
+    // Text on accent-colored badges and buttons.
+    let on_accent = ui.background.strip_alpha();
+    let shadow = Color::rgba(0, 0, 0, 0x66);
+    vec![
+        // Base
+        ("focusBorder", b.border_focused),
+        ("foreground", ui.foreground),
+        ("disabledForeground", ui.foreground_dim),
+        ("descriptionForeground", ui.foreground_muted),
+        ("errorForeground", red),
+        ("icon.foreground", ui.foreground_muted),
+        ("selection.background", ui.selection),
+        ("widget.shadow", shadow),
+        ("widget.border", b.border),
+        ("textLink.foreground", blue),
+        ("textLink.activeForeground", a.blue.bright),
+        ("textBlockQuote.background", ui.background_alt),
+        ("textBlockQuote.border", b.border),
+        ("textCodeBlock.background", ui.background_alt),
+        ("textPreformat.foreground", palette.syntax.lavender),
+        ("progressBar.background", cyan),
+        ("badge.background", cyan),
+        ("badge.foreground", on_accent),
+        // Title bar
+        ("titleBar.activeBackground", ui.background_alt),
+        ("titleBar.activeForeground", ui.foreground),
+        ("titleBar.inactiveBackground", ui.background_alt),
+        ("titleBar.inactiveForeground", ui.foreground_dim),
+        ("titleBar.border", b.border_variant),
+        // Activity bar
+        ("activityBar.background", ui.background_alt),
+        ("activityBar.foreground", ui.foreground),
+        ("activityBar.inactiveForeground", ui.foreground_dim),
+        ("activityBar.border", b.border_variant),
+        ("activityBar.activeBorder", b.border_selected),
+        ("activityBarBadge.background", cyan),
+        ("activityBarBadge.foreground", on_accent),
+        // Side bar
+        ("sideBar.background", ui.background_alt),
+        ("sideBar.foreground", ui.foreground),
+        ("sideBar.border", b.border),
+        ("sideBarTitle.foreground", ui.foreground),
+        ("sideBarSectionHeader.background", ui.background_alt),
+        ("sideBarSectionHeader.foreground", ui.foreground),
+        ("sideBarSectionHeader.border", b.border_variant),
+        // Status bar
+        ("statusBar.background", ui.background_alt),
+        ("statusBar.foreground", ui.foreground_muted),
+        ("statusBar.border", b.border_variant),
+        ("statusBar.noFolderBackground", ui.background_alt),
+        ("statusBar.debuggingBackground", magenta),
+        ("statusBar.debuggingForeground", on_accent),
+        ("statusBarItem.hoverBackground", ui.selection),
+        ("statusBarItem.remoteBackground", cyan),
+        ("statusBarItem.remoteForeground", on_accent),
+        ("statusBarItem.errorBackground", red),
+        ("statusBarItem.errorForeground", ui.foreground),
+        ("statusBarItem.warningBackground", yellow),
+        ("statusBarItem.warningForeground", on_accent),
+        // Tabs, editor groups and breadcrumbs
+        ("editorGroup.border", b.border_variant),
+        ("editorGroup.dropBackground", ui.selection.apply_alpha(0.5)),
+        ("editorGroupHeader.tabsBackground", ui.background_alt),
+        ("editorGroupHeader.tabsBorder", b.border_variant),
+        ("tab.activeBackground", ui.background),
+        ("tab.activeForeground", ui.foreground),
+        ("tab.activeBorderTop", b.border_selected),
+        ("tab.inactiveBackground", ui.background_alt),
+        ("tab.inactiveForeground", ui.foreground_dim),
+        ("tab.hoverBackground", ui.background),
+        ("tab.border", b.border_variant),
+        ("tab.unfocusedActiveForeground", ui.foreground_muted),
+        ("tab.unfocusedInactiveForeground", ui.foreground_dim),
+        ("breadcrumb.background", ui.background),
+        ("breadcrumb.foreground", ui.foreground_muted),
+        ("breadcrumb.focusForeground", ui.foreground),
+        ("breadcrumb.activeSelectionForeground", ui.foreground),
+        ("breadcrumbPicker.background", ui.background_elevated),
+        // Editor
+        ("editor.background", ui.background),
+        ("editor.foreground", ui.foreground),
+        ("editor.lineHighlightBackground", ui.line_highlight),
+        ("editor.selectionBackground", ui.selection),
+        ("editor.selectionForeground", ui.selection_foreground),
+        ("editor.inactiveSelectionBackground", ui.selection.apply_alpha(0.5)),
+        ("editor.selectionHighlightBackground", ui.selection.apply_alpha(0.6)),
+        ("editor.wordHighlightBackground", ui.selection.apply_alpha(0.5)),
+        ("editor.wordHighlightStrongBackground", ui.selection.apply_alpha(0.7)),
+        ("editor.hoverHighlightBackground", ui.selection.apply_alpha(0.4)),
+        ("editor.findMatchBackground", yellow.apply_alpha(0.4)),
+        ("editor.findMatchHighlightBackground", yellow.apply_alpha(0.2)),
+        ("editor.findMatchBorder", yellow),
+        ("editor.findRangeHighlightBackground", ui.line_highlight),
+        ("editor.rangeHighlightBackground", ui.line_highlight),
+        ("editorCursor.foreground", ui.cursor),
+        ("editorCursor.background", ui.cursor_text),
+        ("editorLineNumber.foreground", ui.foreground_dim),
+        ("editorLineNumber.activeForeground", ui.foreground),
+        ("editorIndentGuide.background1", b.border_variant),
+        ("editorIndentGuide.activeBackground1", b.border),
+        ("editorWhitespace.foreground", b.border_variant),
+        ("editorRuler.foreground", b.border_variant),
+        ("editorBracketMatch.background", cyan.apply_alpha(0.2)),
+        ("editorBracketMatch.border", cyan),
+        ("editorBracketHighlight.foreground1", blue),
+        ("editorBracketHighlight.foreground2", magenta),
+        ("editorBracketHighlight.foreground3", cyan),
+        ("editorBracketHighlight.foreground4", yellow),
+        ("editorBracketHighlight.foreground5", green),
+        ("editorBracketHighlight.foreground6", palette.syntax.lavender),
+        ("editorBracketHighlight.unexpectedBracket.foreground", red),
+        ("editorLink.activeForeground", blue),
+        ("editorCodeLens.foreground", ui.foreground_dim),
+        ("editorInlayHint.foreground", ui.foreground_dim),
+        ("editorInlayHint.background", ui.background_alt),
+        ("editorError.foreground", red),
+        ("editorWarning.foreground", yellow),
+        ("editorInfo.foreground", blue),
+        ("editorHint.foreground", cyan),
+        ("editorGutter.background", ui.background),
+        ("editorGutter.addedBackground", green),
+        ("editorGutter.modifiedBackground", blue),
+        ("editorGutter.deletedBackground", red),
+        ("editorOverviewRuler.border", b.border_variant),
+        ("editorOverviewRuler.findMatchForeground", yellow),
+        ("editorOverviewRuler.errorForeground", red),
+        ("editorOverviewRuler.warningForeground", yellow),
+        ("editorOverviewRuler.infoForeground", blue),
+        ("editorOverviewRuler.addedForeground", green),
+        ("editorOverviewRuler.modifiedForeground", blue),
+        ("editorOverviewRuler.deletedForeground", red),
+        // Editor widgets
+        ("editorWidget.background", ui.background_elevated),
+        ("editorWidget.foreground", ui.foreground),
+        ("editorWidget.border", b.border),
+        ("editorSuggestWidget.background", ui.background_elevated),
+        ("editorSuggestWidget.foreground", ui.foreground),
+        ("editorSuggestWidget.border", b.border),
+        ("editorSuggestWidget.selectedBackground", ui.selection),
+        ("editorSuggestWidget.highlightForeground", cyan),
+        ("editorHoverWidget.background", ui.background_elevated),
+        ("editorHoverWidget.border", b.border),
+        // Diff editor
+        ("diffEditor.insertedTextBackground", green.apply_alpha(0.15)),
+        ("diffEditor.removedTextBackground", red.apply_alpha(0.15)),
+        ("diffEditor.insertedLineBackground", green.apply_alpha(0.08)),
+        ("diffEditor.removedLineBackground", red.apply_alpha(0.08)),
+        ("diffEditor.diagonalFill", b.border_variant),
+        // Merge conflicts
+        ("merge.currentHeaderBackground", green.apply_alpha(0.4)),
+        ("merge.currentContentBackground", green.apply_alpha(0.15)),
+        ("merge.incomingHeaderBackground", blue.apply_alpha(0.4)),
+        ("merge.incomingContentBackground", blue.apply_alpha(0.15)),
+        // Minimap
+        ("minimap.background", ui.background),
+        ("minimap.selectionHighlight", ui.selection),
+        ("minimap.findMatchHighlight", yellow),
+        ("minimap.errorHighlight", red),
+        ("minimap.warningHighlight", yellow),
+        ("minimapGutter.addedBackground", green),
+        ("minimapGutter.modifiedBackground", blue),
+        ("minimapGutter.deletedBackground", red),
+        ("minimapSlider.background", b.border.apply_alpha(0.3)),
+        ("minimapSlider.hoverBackground", b.border.apply_alpha(0.5)),
+        ("minimapSlider.activeBackground", b.border.apply_alpha(0.7)),
+        // Scrollbar
+        ("scrollbar.shadow", shadow),
+        ("scrollbarSlider.background", b.border.apply_alpha(0.4)),
+        ("scrollbarSlider.hoverBackground", b.border.apply_alpha(0.6)),
+        ("scrollbarSlider.activeBackground", b.border.apply_alpha(0.8)),
+        // Peek view
+        ("peekView.border", b.border_focused),
+        ("peekViewEditor.background", ui.background_alt),
+        ("peekViewEditor.matchHighlightBackground", yellow.apply_alpha(0.3)),
+        ("peekViewResult.background", ui.background_elevated),
+        ("peekViewResult.fileForeground", ui.foreground),
+        ("peekViewResult.lineForeground", ui.foreground_muted),
+        ("peekViewResult.matchHighlightBackground", yellow.apply_alpha(0.3)),
+        ("peekViewResult.selectionBackground", ui.selection),
+        ("peekViewResult.selectionForeground", ui.selection_foreground),
+        ("peekViewTitle.background", ui.background_elevated),
+        ("peekViewTitleLabel.foreground", ui.foreground),
+        ("peekViewTitleDescription.foreground", ui.foreground_muted),
+        // Panel and terminal
+        ("panel.background", ui.background_alt),
+        ("panel.border", b.border_variant),
+        ("panelTitle.activeForeground", ui.foreground),
+        ("panelTitle.inactiveForeground", ui.foreground_dim),
+        ("panelTitle.activeBorder", b.border_selected),
+        ("panelInput.border", b.border),
+        ("terminal.background", ui.background),
+        ("terminal.foreground", ui.foreground),
+        ("terminal.border", b.border_variant),
+        ("terminal.selectionBackground", ui.selection),
+        ("terminal.selectionForeground", ui.selection_foreground),
+        ("terminalCursor.foreground", ui.cursor),
+        ("terminalCursor.background", ui.cursor_text),
+        // Lists and trees
+        ("list.activeSelectionBackground", ui.selection),
+        ("list.activeSelectionForeground", ui.selection_foreground),
+        ("list.inactiveSelectionBackground", ui.selection.apply_alpha(0.5)),
+        ("list.inactiveSelectionForeground", ui.foreground),
+        ("list.hoverBackground", ui.line_highlight),
+        ("list.hoverForeground", ui.foreground),
+        ("list.focusBackground", ui.selection),
+        ("list.focusOutline", b.border_focused),
+        ("list.highlightForeground", cyan),
+        ("list.errorForeground", red),
+        ("list.warningForeground", yellow),
+        ("list.dropBackground", ui.selection.apply_alpha(0.5)),
+        ("tree.indentGuidesStroke", b.border_variant),
+        // Inputs and buttons
+        ("input.background", ui.background_elevated),
+        ("input.foreground", ui.foreground),
+        ("input.border", b.border),
+        ("input.placeholderForeground", ui.foreground_dim),
+        ("inputOption.activeBorder", b.border_focused),
+        ("inputOption.activeBackground", blue.apply_alpha(0.3)),
+        ("inputValidation.errorBackground", ui.background_elevated),
+        ("inputValidation.errorBorder", red),
+        ("inputValidation.warningBackground", ui.background_elevated),
+        ("inputValidation.warningBorder", yellow),
+        ("inputValidation.infoBackground", ui.background_elevated),
+        ("inputValidation.infoBorder", blue),
+        ("dropdown.background", ui.background_elevated),
+        ("dropdown.foreground", ui.foreground),
+        ("dropdown.border", b.border),
+        ("checkbox.background", ui.background_elevated),
+        ("checkbox.foreground", ui.foreground),
+        ("checkbox.border", b.border),
+        ("button.background", blue),
+        ("button.foreground", on_accent),
+        ("button.hoverBackground", a.blue.bright),
+        ("button.secondaryBackground", ui.background_elevated),
+        ("button.secondaryForeground", ui.foreground),
+        ("button.secondaryHoverBackground", ui.selection),
+        // Menus, quick input and notifications
+        ("menu.background", ui.background_elevated),
+        ("menu.foreground", ui.foreground),
+        ("menu.selectionBackground", ui.selection),
+        ("menu.selectionForeground", ui.selection_foreground),
+        ("menu.separatorBackground", b.border),
+        ("menu.border", b.border),
+        ("quickInput.background", ui.background_elevated),
+        ("quickInput.foreground", ui.foreground),
+        ("quickInputList.focusBackground", ui.selection),
+        ("pickerGroup.foreground", cyan),
+        ("pickerGroup.border", b.border),
+        ("notifications.background", ui.background_elevated),
+        ("notifications.foreground", ui.foreground),
+        ("notifications.border", b.border),
+        ("notificationCenterHeader.background", ui.background_alt),
+        ("notificationsErrorIcon.foreground", red),
+        ("notificationsWarningIcon.foreground", yellow),
+        ("notificationsInfoIcon.foreground", blue),
+        // Git decorations
+        ("gitDecoration.addedResourceForeground", green),
+        ("gitDecoration.modifiedResourceForeground", yellow),
+        ("gitDecoration.deletedResourceForeground", red),
+        ("gitDecoration.renamedResourceForeground", cyan),
+        ("gitDecoration.untrackedResourceForeground", green),
+        ("gitDecoration.ignoredResourceForeground", ui.foreground_dim),
+        ("gitDecoration.conflictingResourceForeground", magenta),
+        ("gitDecoration.stageModifiedResourceForeground", blue),
+        ("gitDecoration.stageDeletedResourceForeground", red),
+        ("gitDecoration.submoduleResourceForeground", cyan),
+        // Settings editor
+        ("settings.headerForeground", ui.foreground),
+        ("settings.modifiedItemIndicator", blue),
+    ]
+}
+
+/// TextMate scopes for each role.
+fn scopes(role: Role) -> &'static [&'static str] {
+    match role {
+        Role::Comment => &["comment", "punctuation.definition.comment"],
+        Role::Keyword => &["keyword", "storage.type", "storage.modifier", "keyword.control"],
+        Role::Function => &["entity.name.function", "support.function", "meta.function-call.generic"],
+        Role::Method => &["entity.name.function.member", "meta.method-call entity.name.function"],
+        Role::Type => &["entity.name.type", "support.type", "support.class", "entity.name.class", "entity.other.inherited-class"],
+        Role::Constructor => &["entity.name.function.constructor", "meta.class.constructor"],
+        Role::String => &["string", "string.quoted", "punctuation.definition.string"],
+        Role::Escape => &["constant.character.escape", "constant.other.placeholder"],
+        Role::Regex => &["string.regexp"],
+        Role::Number => &["constant.numeric"],
+        Role::Boolean => &["constant.language.boolean"],
+        Role::Constant => &["constant.language", "constant.other", "variable.other.constant", "variable.other.enummember"],
+        Role::Operator => &["keyword.operator"],
+        Role::Attribute => &["entity.other.attribute-name", "meta.attribute", "meta.decorator"],
+        Role::Variable => &["variable", "variable.other.readwrite"],
+        Role::Parameter => &["variable.parameter"],
+        Role::Property => &["variable.other.property", "variable.other.object.property", "support.type.property-name", "meta.object-literal.key"],
+        Role::Macro => &["entity.name.function.macro", "entity.name.function.preprocessor", "support.macro"],
+        Role::Namespace => &["entity.name.namespace", "entity.name.module", "storage.modifier.import"],
+        Role::Punctuation => &["punctuation", "meta.brace", "punctuation.separator", "punctuation.terminator"],
+        Role::Tag => &["entity.name.tag", "punctuation.definition.tag"],
+    }
+}
+
+/// Semantic token selectors for each role (standard LSP token types plus the
+/// common `boolean`/`attribute` extensions).
+fn semantic_tokens(role: Role) -> &'static [&'static str] {
+    match role {
+        Role::Comment => &["comment"],
+        Role::Keyword => &["keyword"],
+        Role::Function => &["function"],
+        Role::Method => &["method"],
+        Role::Type => &["type", "class", "struct", "interface", "enum", "typeParameter"],
+        Role::Constructor => &[],
+        Role::String => &["string"],
+        Role::Escape => &[],
+        Role::Regex => &["regexp"],
+        Role::Number => &["number"],
+        Role::Boolean => &["boolean"],
+        Role::Constant => &["enumMember", "variable.readonly"],
+        Role::Operator => &["operator"],
+        Role::Attribute => &["decorator", "attribute"],
+        Role::Variable => &["variable"],
+        Role::Parameter => &["parameter"],
+        Role::Property => &["property"],
+        Role::Macro => &["macro"],
+        Role::Namespace => &["namespace"],
+        Role::Punctuation => &[],
+        Role::Tag => &[],
+    }
+}
+
+/// `semanticTokenColors`: the role styles keyed by semantic token selector.
+fn semantic_token_colors(cfg: &Config, palette: &Palette) -> Value {
+    let mut out = Map::new();
+    for (role, style) in cfg.syntax.styles(palette) {
+        if style == Style::default() {
+            continue;
+        }
+        let mut entry = Map::new();
+        if let Some(c) = style.color { entry.insert("foreground".into(), json!(c)); }
+        if style.bold { entry.insert("bold".into(), json!(true)); }
+        if style.italic { entry.insert("italic".into(), json!(true)); }
+        if style.underline { entry.insert("underline".into(), json!(true)); }
+        for selector in semantic_tokens(role) {
+            out.insert(selector.to_string(), Value::Object(entry.clone()));
+        }
+    }
+    Value::Object(out)
+}
+
+/// `tokenColors` rules, one per role with a style.
+fn token_colors(cfg: &Config, palette: &Palette) -> Value {
+    let rules: Vec<_> = cfg
+        .syntax
+        .styles(palette)
+        .into_iter()
+        .filter(|(_, style)| *style != Style::default())
+        .map(|(role, style)| {
+            let mut settings = json!({});
+            if let Some(c) = style.color { settings["foreground"] = json!(c); }
+            let font: Vec<_> = [(style.italic, "italic"), (style.bold, "bold"), (style.underline, "underline")]
+                .iter()
+                .filter(|(on, _)| *on)
+                .map(|(_, name)| *name)
+                .collect();
+            if !font.is_empty() { settings["fontStyle"] = json!(font.join(" ")); }
+            json!({"name": role.name(), "scope": scopes(role), "settings": settings})
+        })
+        .collect();
+    Value::Array(rules)
+}
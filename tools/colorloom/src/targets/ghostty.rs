@@ -0,0 +1,64 @@
+use super::{Capabilities, Output, Target};
+use crate::config::{self, Config, Variant};
+use anyhow::Result;
+use std::path::Path;
+
+pub struct Ghostty;
+
+impl Target for Ghostty {
+    fn id(&self) -> &'static str {
+        "ghostty"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ghostty terminal theme"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: true,
+            variants: true,
+            alpha: false,
+            blur: true,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String {
+        let suffix = variant.map(|v| format!("-{}", v.name)).unwrap_or_default();
+        format!("{}{}", cfg.meta.slug(), suffix)
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, _root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        let mut outputs = Vec::new();
+        for v in self.variants(cfg) {
+            let background = self.window_background(cfg, target, v);
+            let palette = self.palette(cfg, target, v);
+            let ui = &palette.ui;
+            let name = self.file_name(cfg, target, Some(v));
+            let mut out = String::new();
+            out.push_str(&format!("# {} for Ghostty\n", cfg.meta.name));
+            let colors = palette.base.ansi.colors();
+            for (i, (_, c)) in colors.iter().enumerate() {
+                out.push_str(&format!("palette = {}={}\n", i, c.base));
+            }
+            for (i, (_, c)) in colors.iter().enumerate() {
+                out.push_str(&format!("palette = {}={}\n", i + 8, c.bright));
+            }
+            out.push_str(&format!("background = {}\n", background.strip_alpha()));
+            out.push_str(&format!("foreground = {}\n", ui.foreground));
+            out.push_str(&format!("cursor-color = {}\n", ui.cursor));
+            out.push_str(&format!("cursor-text = {}\n", ui.cursor_text));
+            out.push_str(&format!("selection-background = {}\n", ui.selection));
+            out.push_str(&format!("selection-foreground = {}\n", ui.selection_foreground));
+            if !background.is_opaque() {
+                out.push_str(&format!("background-opacity = {:.2}\n", background.a as f32 / 255.0));
+            }
+            if let Some(radius) = v.blur_radius {
+                out.push_str(&format!("background-blur-radius = {}\n", radius));
+            }
+            outputs.push(Output::new(dir.join(name), out));
+        }
+        Ok(outputs)
+    }
+}
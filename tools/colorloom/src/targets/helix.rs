@@ -0,0 +1,278 @@
+use super::{Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Palette, Variant};
+use crate::syntax::{Role, Style};
+use anyhow::Result;
+use std::{fmt::Write, path::Path};
+
+pub struct Helix;
+
+impl Target for Helix {
+    fn id(&self) -> &'static str {
+        "helix"
+    }
+
+    fn description(&self) -> &'static str {
+        "Helix editor theme (TOML)"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: true,
+            variants: true,
+            alpha: false,
+            blur: false,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String {
+        let suffix = variant.map(|v| format!("-{}", v.name)).unwrap_or_default();
+        format!("{}{}.toml", cfg.meta.slug(), suffix)
+    }
+
+    /// The first variant is written in full; the others `inherits` from it
+    /// and only list the scopes and palette entries that differ.
+    fn render(&self, cfg: &Config, target: &config::Target, _root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        let mut outputs = Vec::new();
+        let mut parent: Option<(String, Theme)> = None;
+        for v in self.variants(cfg) {
+            let palette = self.palette(cfg, target, v);
+            // Translucent variants leave the background to the terminal.
+            let transparent = !self.window_background(cfg, target, v).is_opaque();
+            let theme = Theme::new(cfg, &palette, transparent);
+            let name = self.file_name(cfg, target, Some(v));
+            let mut out = format!("# {} for Helix\n", cfg.meta.name);
+            match &parent {
+                Some((parent_name, base)) => {
+                    let _ = writeln!(out, "inherits = \"{}\"", parent_name);
+                    theme.write(&mut out, Some(base));
+                }
+                None => theme.write(&mut out, None),
+            }
+            outputs.push(Output::new(dir.join(&name), out));
+            if parent.is_none() {
+                parent = Some((name.strip_suffix(".toml").unwrap_or(&name).to_string(), theme));
+            }
+        }
+        Ok(outputs)
+    }
+}
+
+/// A rendered theme: scope -> inline table, and the named colors they use.
+struct Theme {
+    scopes: Vec<(String, String)>,
+    palette: Vec<(&'static str, Color)>,
+}
+
+impl Theme {
+    fn new(cfg: &Config, palette: &Palette, transparent: bool) -> Self {
+        let colors = colors(palette);
+        let name = |c: Color| colors.iter().find(|(_, p)| *p == c).map(|(n, _)| n.to_string()).unwrap_or_else(|| c.to_string());
+        let mut scopes: Vec<(String, String)> = ui_scopes(transparent).iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        for (role, style) in cfg.syntax.styles(palette) {
+            if style == Style::default() {
+                continue;
+            }
+            let value = style_table(&style, &name);
+            for scope in scopes_for(role) {
+                scopes.push((scope.to_string(), value.clone()));
+            }
+        }
+        Theme { scopes, palette: colors }
+    }
+
+    /// Append the scopes and `[palette]`, skipping entries identical in `base`.
+    /// Helix merges an inheriting theme key by key, so scopes `base` styles
+    /// but this theme doesn't are cleared with an explicit `{}`.
+    fn write(&self, out: &mut String, base: Option<&Theme>) {
+        let mut scopes = String::new();
+        for (scope, value) in &self.scopes {
+            if base.is_some_and(|b| b.scopes.iter().any(|(s, v)| s == scope && v == value)) {
+                continue;
+            }
+            let _ = writeln!(scopes, "\"{}\" = {}", scope, value);
+        }
+        for (scope, _) in base.map(|b| b.scopes.as_slice()).unwrap_or_default() {
+            if !self.scopes.iter().any(|(s, _)| s == scope) {
+                let _ = writeln!(scopes, "\"{}\" = {{}}", scope);
+            }
+        }
+        let mut palette = String::new();
+        for (name, color) in &self.palette {
+            if base.is_some_and(|b| b.palette.iter().any(|(n, c)| n == name && c == color)) {
+                continue;
+            }
+            let _ = writeln!(palette, "{} = \"{}\"", name, color);
+        }
+        if !scopes.is_empty() { let _ = write!(out, "\n{}", scopes); }
+        if !palette.is_empty() { let _ = write!(out, "\n[palette]\n{}", palette); }
+    }
+}
+
+/// Inline table for a `[syntax]` role, referring to palette names where the
+/// color is one of them.
+fn style_table(style: &Style, name: &impl Fn(Color) -> String) -> String {
+    let mut parts = Vec::new();
+    if let Some(c) = style.color { parts.push(format!("fg = \"{}\"", name(c))); }
+    let modifiers: Vec<_> = [(style.bold, "\"bold\""), (style.italic, "\"italic\"")].iter().filter(|(on, _)| *on).map(|(_, m)| *m).collect();
+    if !modifiers.is_empty() { parts.push(format!("modifiers = [{}]", modifiers.join(", "))); }
+    if style.underline { parts.push("underline = { style = \"line\" }".to_string()); }
+    format!("{{ {} }}", parts.join(", "))
+}
+
+/// Named colors for `[palette]`. Role colors are looked up here by value, so
+/// the syntax and ANSI names come first and win over UI colors they share a
+/// value with. Everything is opaque since Helix only accepts `#rrggbb`.
+fn colors(palette: &Palette) -> Vec<(&'static str, Color)> {
+    let (ui, border, syntax) = (&palette.ui, &palette.border, &palette.syntax);
+    const BRIGHT: [&str; 8] = ["bright_black", "bright_red", "bright_green", "bright_yellow", "bright_blue", "bright_magenta", "bright_cyan", "bright_white"];
+    let ansi = palette.base.ansi.colors();
+    let mut colors = vec![
+        ("teal", syntax.teal),
+        ("blue_green", syntax.blue_green),
+        ("lavender", syntax.lavender),
+        ("gray", syntax.gray),
+    ];
+    colors.extend(ansi.iter().map(|(name, c)| (*name, c.base)));
+    colors.extend(ansi.iter().zip(BRIGHT).map(|((_, c), name)| (name, c.bright)));
+    colors.extend([
+        ("bg", ui.background),
+        ("bg_alt", ui.background_alt),
+        ("bg_elevated", ui.background_elevated),
+        ("fg", ui.foreground),
+        ("fg_muted", ui.foreground_muted),
+        ("fg_dim", ui.foreground_dim),
+        ("selection", ui.selection),
+        ("selection_fg", ui.selection_foreground),
+        ("cursor", ui.cursor),
+        ("cursor_fg", ui.cursor_text),
+        ("line", ui.line_highlight),
+        ("border", border.border),
+        ("border_variant", border.border_variant),
+        ("border_focused", border.border_focused),
+        ("border_selected", border.border_selected),
+    ]);
+    colors
+}
+
+/// Interface, diagnostic, diff and markup scopes in terms of `[palette]` names.
+fn ui_scopes(transparent: bool) -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("ui.background", if transparent { "{}" } else { "{ bg = \"bg\" }" }),
+        ("ui.text", "\"fg\""),
+        ("ui.text.focus", "{ fg = \"fg\", modifiers = [\"bold\"] }"),
+        ("ui.text.inactive", "\"fg_dim\""),
+        ("ui.cursor", "{ fg = \"cursor_fg\", bg = \"cursor\" }"),
+        ("ui.cursor.primary", "{ fg = \"cursor_fg\", bg = \"cursor\" }"),
+        ("ui.cursor.match", "{ bg = \"selection\", modifiers = [\"bold\"] }"),
+        ("ui.cursorline.primary", "{ bg = \"line\" }"),
+        ("ui.selection", "{ bg = \"selection\" }"),
+        ("ui.selection.primary", "{ fg = \"selection_fg\", bg = \"selection\" }"),
+        ("ui.linenr", "\"fg_dim\""),
+        ("ui.linenr.selected", "\"fg\""),
+        ("ui.statusline", "{ fg = \"fg\", bg = \"bg_alt\" }"),
+        ("ui.statusline.inactive", "{ fg = \"fg_dim\", bg = \"bg_alt\" }"),
+        ("ui.statusline.normal", "{ fg = \"bg\", bg = \"blue\", modifiers = [\"bold\"] }"),
+        ("ui.statusline.insert", "{ fg = \"bg\", bg = \"green\", modifiers = [\"bold\"] }"),
+        ("ui.statusline.select", "{ fg = \"bg\", bg = \"magenta\", modifiers = [\"bold\"] }"),
+        ("ui.menu", "{ fg = \"fg\", bg = \"bg_elevated\" }"),
+        ("ui.menu.selected", "{ fg = \"selection_fg\", bg = \"selection\" }"),
+        ("ui.menu.scroll", "{ fg = \"border\", bg = \"bg_elevated\" }"),
+        ("ui.popup", "{ fg = \"fg\", bg = \"bg_elevated\" }"),
+        ("ui.popup.info", "{ fg = \"fg\", bg = \"bg_elevated\" }"),
+        ("ui.help", "{ fg = \"fg\", bg = \"bg_elevated\" }"),
+        ("ui.window", "\"border\""),
+        ("ui.bufferline", "{ fg = \"fg_dim\", bg = \"bg_alt\" }"),
+        ("ui.bufferline.active", "{ fg = \"fg\", bg = \"bg\" }"),
+        ("ui.virtual.whitespace", "\"border_variant\""),
+        ("ui.virtual.indent-guide", "\"border_variant\""),
+        ("ui.virtual.ruler", "{ bg = \"bg_alt\" }"),
+        ("ui.virtual.inlay-hint", "\"fg_dim\""),
+        ("ui.virtual.jump-label", "{ fg = \"yellow\", modifiers = [\"bold\"] }"),
+        ("error", "\"red\""),
+        ("warning", "\"yellow\""),
+        ("info", "\"blue\""),
+        ("hint", "\"cyan\""),
+        ("diagnostic.error", "{ underline = { color = \"red\", style = \"curl\" } }"),
+        ("diagnostic.warning", "{ underline = { color = \"yellow\", style = \"curl\" } }"),
+        ("diagnostic.info", "{ underline = { color = \"blue\", style = \"curl\" } }"),
+        ("diagnostic.hint", "{ underline = { color = \"cyan\", style = \"curl\" } }"),
+        ("diagnostic.unnecessary", "{ modifiers = [\"dim\"] }"),
+        ("diagnostic.deprecated", "{ modifiers = [\"crossed_out\"] }"),
+        ("diff.plus", "\"green\""),
+        ("diff.minus", "\"red\""),
+        ("diff.delta", "\"blue\""),
+        ("markup.heading", "{ fg = \"blue\", modifiers = [\"bold\"] }"),
+        ("markup.list", "\"magenta\""),
+        ("markup.bold", "{ modifiers = [\"bold\"] }"),
+        ("markup.italic", "{ modifiers = [\"italic\"] }"),
+        ("markup.strikethrough", "{ modifiers = [\"crossed_out\"] }"),
+        ("markup.link.url", "{ fg = \"blue\", underline = { style = \"line\" } }"),
+        ("markup.link.text", "\"lavender\""),
+        ("markup.quote", "{ fg = \"fg_muted\", modifiers = [\"italic\"] }"),
+        ("markup.raw", "\"teal\""),
+    ]
+}
+
+/// Helix scopes for each role. Helix falls back to the longest matching
+/// prefix, so `function` also covers `function.builtin`.
+fn scopes_for(role: Role) -> &'static [&'static str] {
+    match role {
+        Role::Comment => &["comment"],
+        Role::Keyword => &["keyword"],
+        Role::Function => &["function"],
+        Role::Method => &["function.method"],
+        Role::Type => &["type"],
+        Role::Constructor => &["constructor", "type.enum.variant"],
+        Role::String => &["string"],
+        Role::Escape => &["constant.character.escape"],
+        Role::Regex => &["string.regexp"],
+        Role::Number => &["constant.numeric"],
+        Role::Boolean => &["constant.builtin.boolean"],
+        Role::Constant => &["constant"],
+        Role::Operator => &["operator"],
+        Role::Attribute => &["attribute"],
+        Role::Variable => &["variable"],
+        Role::Parameter => &["variable.parameter"],
+        Role::Property => &["variable.other.member"],
+        Role::Macro => &["function.macro"],
+        Role::Namespace => &["namespace"],
+        Role::Punctuation => &["punctuation"],
+        Role::Tag => &["tag"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn theme(scopes: &[(&str, &str)], bg: Color) -> Theme {
+        Theme { scopes: scopes.iter().map(|(s, v)| (s.to_string(), v.to_string())).collect(), palette: vec![("bg", bg)] }
+    }
+
+    fn child(theme: &Theme, base: &Theme) -> String {
+        let mut out = String::new();
+        theme.write(&mut out, Some(base));
+        out
+    }
+
+    #[test]
+    fn identical_variant_only_inherits() {
+        let base = theme(&[("comment", "\"gray\""), ("keyword", "\"red\"")], Color::rgb(0, 0, 0));
+        assert_eq!(child(&theme(&[("comment", "\"gray\""), ("keyword", "\"red\"")], Color::rgb(0, 0, 0)), &base), "");
+    }
+
+    #[test]
+    fn changed_scopes_and_colors_are_written() {
+        let base = theme(&[("comment", "\"gray\""), ("keyword", "\"red\"")], Color::rgb(0, 0, 0));
+        let variant = theme(&[("comment", "\"gray\""), ("keyword", "{ fg = \"red\", modifiers = [\"bold\"] }")], Color::rgb(1, 2, 3));
+        assert_eq!(child(&variant, &base), "\n\"keyword\" = { fg = \"red\", modifiers = [\"bold\"] }\n\n[palette]\nbg = \"#010203\"\n");
+    }
+
+    #[test]
+    fn cleared_scope_overrides_base() {
+        let base = theme(&[("comment", "\"gray\""), ("keyword", "\"red\"")], Color::rgb(0, 0, 0));
+        assert_eq!(child(&theme(&[("comment", "\"gray\"")], Color::rgb(0, 0, 0)), &base), "\n\"keyword\" = {}\n");
+    }
+}
@@ -0,0 +1,115 @@
+use super::{Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Variant};
+use anyhow::Result;
+use std::{fmt::Write, path::Path};
+
+pub struct Iterm2;
+
+impl Target for Iterm2 {
+    fn id(&self) -> &'static str {
+        "iterm2"
+    }
+
+    fn description(&self) -> &'static str {
+        "iTerm2 color preset (.itermcolors plist)"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: true,
+            variants: true,
+            alpha: false,
+            blur: false,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String {
+        let suffix = variant.map(|v| format!("-{}", v.name)).unwrap_or_default();
+        format!("{}{}.itermcolors", cfg.meta.slug(), suffix)
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, _root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        let mut outputs = Vec::new();
+        for v in self.variants(cfg) {
+            let palette = self.palette(cfg, target, v);
+            let (ui, ansi) = (&palette.ui, &palette.base.ansi);
+            let colors = ansi.colors();
+            let mut entries: Vec<(String, Color)> = colors
+                .iter()
+                .map(|(_, c)| c.base)
+                .chain(colors.iter().map(|(_, c)| c.bright))
+                .enumerate()
+                .map(|(i, c)| (format!("Ansi {} Color", i), c))
+                .collect();
+            entries.extend([
+                ("Background Color", ui.background),
+                ("Badge Color", ansi.magenta.base.apply_alpha(0.5)),
+                ("Bold Color", ui.foreground),
+                ("Cursor Color", ui.cursor),
+                ("Cursor Guide Color", ui.line_highlight),
+                ("Cursor Text Color", ui.cursor_text),
+                ("Foreground Color", ui.foreground),
+                ("Link Color", ansi.blue.base),
+                ("Selected Text Color", ui.selection_foreground),
+                ("Selection Color", ui.selection),
+                ("Tab Color", ui.background_alt),
+            ].map(|(k, c)| (k.to_string(), c)));
+            outputs.push(Output::new(dir.join(self.file_name(cfg, target, Some(v))), plist(&entries)));
+        }
+        Ok(outputs)
+    }
+}
+
+/// iTerm2 preset: a dictionary of color dictionaries with sRGB float components.
+fn plist(entries: &[(String, Color)]) -> String {
+    let mut out = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+        "<plist version=\"1.0\">\n<dict>\n",
+    ));
+    let component = |v: u8| v as f64 / 255.0;
+    for (key, c) in entries {
+        let _ = writeln!(out, "\t<key>{}</key>\n\t<dict>", key);
+        let _ = writeln!(out, "\t\t<key>Alpha Component</key>\n\t\t<real>{}</real>", component(c.a));
+        let _ = writeln!(out, "\t\t<key>Blue Component</key>\n\t\t<real>{}</real>", component(c.b));
+        out.push_str("\t\t<key>Color Space</key>\n\t\t<string>sRGB</string>\n");
+        let _ = writeln!(out, "\t\t<key>Green Component</key>\n\t\t<real>{}</real>", component(c.g));
+        let _ = writeln!(out, "\t\t<key>Red Component</key>\n\t\t<real>{}</real>", component(c.r));
+        out.push_str("\t</dict>\n");
+    }
+    out.push_str("</dict>\n</plist>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::targets::test_config;
+    use plist::{Dictionary, Value};
+
+    #[test]
+    fn presets_parse_as_color_plists() {
+        let cfg = test_config();
+        let target = cfg.targets.iter().find(|t| t.kind() == "iterm2").unwrap();
+        let outputs = Iterm2.render(&cfg, target, Path::new(".")).unwrap();
+        assert_eq!(outputs.len(), Iterm2.variants(&cfg).len());
+        for out in &outputs {
+            let preset: Dictionary = plist::from_bytes(out.contents.as_bytes()).unwrap();
+            let ansi = preset.keys().filter(|k| k.starts_with("Ansi ")).count();
+            assert_eq!(ansi, 16);
+            for i in 0..16 {
+                assert!(preset.contains_key(&format!("Ansi {} Color", i)), "missing Ansi {} Color", i);
+            }
+            for (key, color) in &preset {
+                let color = color.as_dictionary().unwrap_or_else(|| panic!("{} is not a dictionary", key));
+                assert_eq!(color.get("Color Space").and_then(Value::as_string), Some("sRGB"), "{}", key);
+                for component in ["Red Component", "Green Component", "Blue Component", "Alpha Component"] {
+                    let v = color.get(component).and_then(Value::as_real).unwrap_or_else(|| panic!("{} has no {}", key, component));
+                    assert!((0.0..=1.0).contains(&v), "{} {} = {}", key, component, v);
+                }
+            }
+        }
+    }
+}
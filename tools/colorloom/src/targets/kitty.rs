@@ -0,0 +1,89 @@
+use super::{Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Variant};
+use anyhow::Result;
+use std::{fmt::Write, path::Path};
+
+pub struct Kitty;
+
+impl Target for Kitty {
+    fn id(&self) -> &'static str {
+        "kitty"
+    }
+
+    fn description(&self) -> &'static str {
+        "Kitty terminal theme (.conf)"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: true,
+            variants: true,
+            alpha: false,
+            blur: true,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String {
+        let suffix = variant.map(|v| format!("-{}", v.name)).unwrap_or_default();
+        format!("{}{}.conf", cfg.meta.slug(), suffix)
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, _root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        let mut outputs = Vec::new();
+        for v in self.variants(cfg) {
+            let background = self.window_background(cfg, target, v);
+            let palette = self.palette(cfg, target, v);
+            let (ui, ansi, border) = (&palette.ui, &palette.base.ansi, &palette.border);
+            let title = self.variant_title(cfg, v);
+            // Header read by `kitten themes`.
+            let mut out = String::from("# vim:ft=kitty\n\n");
+            let _ = writeln!(out, "## name: {}", title);
+            if let Some(author) = &cfg.meta.author { let _ = writeln!(out, "## author: {}", author); }
+            if let Some(license) = &cfg.meta.license { let _ = writeln!(out, "## license: {}", license); }
+            if let Some(blurb) = &cfg.meta.description { let _ = writeln!(out, "## blurb: {}", blurb); }
+            section(&mut out, "Basic colors", &[
+                ("foreground", ui.foreground),
+                ("background", background.strip_alpha()),
+                ("selection_foreground", ui.selection_foreground),
+                ("selection_background", ui.selection),
+            ]);
+            section(&mut out, "Cursor", &[("cursor", ui.cursor), ("cursor_text_color", ui.cursor_text)]);
+            section(&mut out, "URLs", &[("url_color", ansi.blue.base)]);
+            section(&mut out, "Window borders", &[
+                ("active_border_color", border.border_focused),
+                ("inactive_border_color", border.border_variant),
+                ("bell_border_color", ansi.yellow.base),
+            ]);
+            section(&mut out, "Tab bar", &[
+                ("active_tab_foreground", ui.foreground),
+                ("active_tab_background", ui.background_elevated),
+                ("inactive_tab_foreground", ui.foreground_dim),
+                ("inactive_tab_background", ui.background_alt),
+                ("tab_bar_background", ui.background_alt),
+            ]);
+            let colors = ansi.colors();
+            let slots: Vec<_> = colors.iter().map(|(_, c)| c.base).chain(colors.iter().map(|(_, c)| c.bright)).collect();
+            out.push_str("\n# The 16 terminal colors\n");
+            for (i, c) in slots.iter().enumerate() {
+                let _ = writeln!(out, "color{} {}", i, c);
+            }
+            if !background.is_opaque() {
+                let _ = writeln!(out, "\nbackground_opacity {:.2}", background.a as f32 / 255.0);
+            }
+            if let Some(radius) = v.blur_radius {
+                let _ = writeln!(out, "background_blur {}", radius);
+            }
+            outputs.push(Output::new(dir.join(self.file_name(cfg, target, Some(v))), out));
+        }
+        Ok(outputs)
+    }
+}
+
+fn section(out: &mut String, comment: &str, entries: &[(&str, Color)]) {
+    let _ = writeln!(out, "\n# {}", comment);
+    for (key, color) in entries {
+        let _ = writeln!(out, "{} {}", key, color);
+    }
+}
@@ -0,0 +1,274 @@
+mod alacritty;
+mod cursor;
+mod ghostty;
+mod helix;
+mod iterm2;
+mod kitty;
+mod neovim;
+mod template;
+mod website;
+mod wezterm;
+mod windows_terminal;
+mod zed;
+
+pub use alacritty::Alacritty;
+pub use cursor::Cursor;
+pub use ghostty::Ghostty;
+pub use helix::Helix;
+pub use iterm2::Iterm2;
+pub use kitty::Kitty;
+pub use neovim::Neovim;
+pub use template::Template;
+pub use website::Website;
+pub use wezterm::Wezterm;
+pub use windows_terminal::WindowsTerminal;
+pub use zed::Zed;
+
+use crate::color::Color;
+use crate::config::{self, Config, Variant};
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// What a generator can express in its output format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Writes one file per variant rather than a single file for the whole theme.
+    pub per_variant_files: bool,
+    /// Output reflects the configured variants at all.
+    pub variants: bool,
+    /// The format accepts translucent (`#rrggbbaa`) colors.
+    pub alpha: bool,
+    /// The format has a native background blur setting.
+    pub blur: bool,
+}
+
+/// A rendered file. `path` is relative to the project root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+impl Output {
+    pub fn new(path: impl Into<PathBuf>, contents: impl Into<String>) -> Self {
+        Output { path: path.into(), contents: contents.into() }
+    }
+
+    /// Write under `root`, creating parent directories as needed.
+    pub fn write(&self, root: &Path) -> Result<()> {
+        let path = root.join(&self.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &self.contents).with_context(|| format!("writing {}", path.display()))
+    }
+}
+
+/// A theme emitter for one tool, selected by the `kind` (or `id`) of a `[[targets]]` entry.
+pub trait Target: Send + Sync {
+    /// Identifier matched against `[[targets]].kind`, falling back to `[[targets]].id`.
+    fn id(&self) -> &'static str;
+
+    /// One-line description shown by `colorloom list`.
+    fn description(&self) -> &'static str;
+
+    fn capabilities(&self) -> Capabilities;
+
+    /// File name used when the `[[targets]]` entry sets neither `out_names` nor `out_file`.
+    /// `variant` is `None` for generators that write a single file.
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String;
+
+    /// Whether `variant` should be emitted. Defaults to every variant.
+    fn supports_variant(&self, _variant: &Variant) -> bool {
+        true
+    }
+
+    /// Render every file for `target` in memory. `root` is only for reading
+    /// inputs such as templates; nothing is written.
+    fn render(&self, cfg: &Config, target: &config::Target, root: &Path) -> Result<Vec<Output>>;
+
+    /// Files other than the theme config that `render` reads, relative to the project root.
+    fn inputs(&self, _target: &config::Target) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    /// Output file name for `variant`, honouring `out_names`/`out_file` from the config.
+    fn file_name(&self, cfg: &Config, target: &config::Target, variant: Option<&Variant>) -> String {
+        let configured = match variant {
+            Some(v) => target.out_names.as_ref().and_then(|m| m.get(&v.name)).cloned(),
+            None => target.out_file.clone(),
+        };
+        configured.unwrap_or_else(|| self.default_file_name(cfg, variant))
+    }
+
+    /// Palette for `variant` as this target should emit it, including the
+    /// variant's overrides for `target` (see [`Variant::palette`]). Translucent
+    /// colors are kept when the format supports alpha, and every section is
+    /// flattened over the variant's backdrop otherwise.
+    fn palette(&self, cfg: &Config, target: &config::Target, variant: &Variant) -> config::Palette {
+        let palette = variant.palette(&cfg.palette, Some(&target.id));
+        if self.capabilities().alpha { palette } else { palette.flatten(variant.backdrop()) }
+    }
+
+    /// The variant's background before flattening, for the window itself.
+    /// Terminals apply their own window opacity (and blur) to the background,
+    /// so it keeps its RGB and alpha while everything drawn on top comes from
+    /// [`Target::palette`], flattened as usual. Terminal-hosted editors check
+    /// `is_opaque()` on it to decide whether to leave the background to the
+    /// terminal.
+    fn window_background(&self, cfg: &Config, target: &config::Target, variant: &Variant) -> Color {
+        variant.palette(&cfg.palette, Some(&target.id)).ui.background
+    }
+
+    /// Variants from `cfg` this generator emits, in config order.
+    fn variants<'a>(&self, cfg: &'a Config) -> Vec<&'a Variant> {
+        cfg.variants.iter().filter(|v| self.supports_variant(v)).collect()
+    }
+
+    /// Display name for `variant`: the theme name, with the capitalized
+    /// variant name appended for anything but `base`.
+    fn variant_title(&self, cfg: &Config, variant: &Variant) -> String {
+        variant_label(cfg, variant, " {}")
+    }
+}
+
+/// The set of generators `colorloom` can dispatch `[[targets]]` entries to.
+pub struct Registry {
+    targets: Vec<Box<dyn Target>>,
+}
+
+impl Registry {
+    /// An empty registry; see [`Registry::with_builtins`] for the in-tree generators.
+    pub fn new() -> Self {
+        Registry { targets: Vec::new() }
+    }
+
+    pub fn with_builtins() -> Self {
+        let mut reg = Registry::new();
+        reg.register_builtins().expect("built-in generator ids are unique");
+        reg
+    }
+
+    fn register_builtins(&mut self) -> Result<()> {
+        self.register(Ghostty)?;
+        self.register(Alacritty)?;
+        self.register(Kitty)?;
+        self.register(Wezterm)?;
+        self.register(WindowsTerminal)?;
+        self.register(Iterm2)?;
+        self.register(Zed)?;
+        self.register(Cursor)?;
+        self.register(Neovim)?;
+        self.register(Helix)?;
+        self.register(Website)?;
+        self.register(Template)?;
+        Ok(())
+    }
+
+    /// Add a generator. Fails if one with the same id is already registered.
+    pub fn register<T: Target + 'static>(&mut self, target: T) -> Result<()> {
+        if self.get(target.id()).is_some() {
+            bail!("a generator with id `{}` is already registered", target.id());
+        }
+        self.targets.push(Box::new(target));
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&dyn Target> {
+        self.targets.iter().find(|t| t.id() == id).map(|t| t.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Target> {
+        self.targets.iter().map(|t| t.as_ref())
+    }
+
+    pub fn ids(&self) -> Vec<&'static str> {
+        self.targets.iter().map(|t| t.id()).collect()
+    }
+
+    pub fn render(&self, cfg: &Config, target: &config::Target, root: &Path) -> Result<Vec<Output>> {
+        let generator = self
+            .get(target.kind())
+            .ok_or_else(|| anyhow!("Unknown target kind: {} (available: {})", target.kind(), self.ids().join(", ")))?;
+        generator.render(cfg, target, root)
+    }
+
+    /// Extra input files for `target`; empty if its kind is unknown.
+    pub fn inputs(&self, target: &config::Target) -> Vec<PathBuf> {
+        self.get(target.kind()).map(|g| g.inputs(target)).unwrap_or_default()
+    }
+
+    /// Render `target` and write the result under `root`.
+    pub fn generate(&self, cfg: &Config, target: &config::Target, root: &Path) -> Result<()> {
+        for out in self.render(cfg, target, root)? {
+            out.write(root)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Registry::with_builtins()
+    }
+}
+
+/// The theme name for `base`; for other variants, the theme name followed by
+/// `suffix` with its `{}` replaced by the capitalized variant name.
+pub(crate) fn variant_label(cfg: &Config, variant: &Variant, suffix: &str) -> String {
+    match variant.name.as_str() {
+        "base" => cfg.meta.name.clone(),
+        other => format!("{}{}", cfg.meta.name, suffix.replace("{}", &capitalize(other))),
+    }
+}
+
+pub(crate) fn capitalize(s: &str) -> String {
+    let mut ch = s.chars();
+    match ch.next() {
+        None => String::new(),
+        Some(f) => f.to_uppercase().collect::<String>() + ch.as_str(),
+    }
+}
+
+/// The repository's own theme.toml, for generator tests.
+#[cfg(test)]
+pub(crate) fn test_config() -> Config {
+    Config::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../../theme.toml")).expect("theme.toml loads")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_free_targets_flatten_every_section() {
+        let mut cfg = test_config();
+        let p = &mut cfg.palette;
+        p.base.ansi.red.base = p.base.ansi.red.base.apply_alpha(0.5);
+        p.base.ansi.blue.bright = p.base.ansi.blue.bright.apply_alpha(0.5);
+        p.syntax.teal = p.syntax.teal.apply_alpha(0.5);
+        p.border.border_focused = p.border.border_focused.apply_alpha(0.5);
+        let eight_digit = regex::Regex::new(r"#[0-9a-fA-F]{8}\b").unwrap();
+        let reg = Registry::with_builtins();
+        for target in &cfg.targets {
+            let generator = reg.get(target.kind()).unwrap();
+            if generator.capabilities().alpha {
+                continue;
+            }
+            for out in generator.render(&cfg, target, Path::new(".")).unwrap() {
+                let found = eight_digit.find(&out.contents).map(|m| m.as_str());
+                assert_eq!(found, None, "{} has a translucent color", out.path.display());
+            }
+        }
+    }
+
+    #[test]
+    fn register_rejects_duplicate_ids() {
+        let mut reg = Registry::with_builtins();
+        assert!(reg.register(Ghostty).is_err());
+        assert_eq!(reg.ids().iter().filter(|id| **id == "ghostty").count(), 1);
+    }
+}
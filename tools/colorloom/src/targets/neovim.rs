@@ -0,0 +1,586 @@
+use super::{Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Palette, Variant};
+use crate::syntax::{Role, Style};
+use anyhow::{anyhow, Result};
+use std::{fmt::Write, path::Path};
+
+pub struct Neovim;
+
+impl Target for Neovim {
+    fn id(&self) -> &'static str {
+        "neovim"
+    }
+
+    fn description(&self) -> &'static str {
+        "Neovim Lua colorscheme module with setup()"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: true,
+            variants: true,
+            alpha: false,
+            blur: false,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String {
+        let suffix = variant.map(|v| format!("-{}", v.name)).unwrap_or_default();
+        format!("{}{}.lua", cfg.meta.slug(), suffix)
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, _root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        // `path` is the runtime's `colors/` directory; Lua modules go next to it under `lua/`.
+        let lua_dir = dir.parent().unwrap_or(dir).join("lua");
+        let module = cfg.meta.slug();
+        let plugins = plugins(target)?;
+        let variants = self.variants(cfg);
+        let default_variant = variants.first().map(|v| v.name.as_str()).unwrap_or("base");
+        let mut outputs = Vec::new();
+        let mut palettes = String::new();
+        for v in &variants {
+            let palette = self.palette(cfg, target, v);
+            let name = self.file_name(cfg, target, Some(v));
+            // `colors_name` must match the `:colorscheme` name for lualine's `auto` theme to find its theme module.
+            let colors_name = name.strip_suffix(".lua").unwrap_or(&name);
+            // Translucent variants default to leaving the editor background to
+            // the terminal, which is what supplies the transparency and blur.
+            let transparent = !self.window_background(cfg, target, v).is_opaque();
+            palettes.push_str(&variant_palette(cfg, v, &palette, colors_name, transparent));
+            // The default variant's shim loads without an argument so `setup({ variant = ... })` applies to it.
+            let variant_arg = if v.name == default_variant { String::new() } else { lua_str(&v.name) };
+            let shim = format!("-- Generated by colorloom\nrequire({}).load({})\n", lua_str(&module), variant_arg);
+            outputs.push(Output::new(dir.join(&name), shim));
+            if plugins.contains(&"lualine") {
+                let path = lua_dir.join("lualine/themes").join(format!("{}.lua", colors_name));
+                outputs.push(Output::new(path, lualine(&module, &v.name)));
+            }
+        }
+        let module_dir = lua_dir.join(&module);
+        outputs.push(Output::new(module_dir.join("init.lua"), init_module(cfg, &module, default_variant)));
+        outputs.push(Output::new(module_dir.join("palette.lua"), format!("-- Generated by colorloom\nreturn {{\n{}}}\n", palettes)));
+        outputs.push(Output::new(module_dir.join("groups.lua"), groups_module(&plugins)));
+        Ok(outputs)
+    }
+}
+
+/// `lua/<slug>/init.lua`: `setup()` stores options, `load()` applies a variant.
+fn init_module(cfg: &Config, module: &str, default_variant: &str) -> String {
+    format!(
+        r#"-- Generated by colorloom
+local M = {{}}
+
+M.config = {{
+  -- Palette to use when loading without an explicit variant.
+  variant = {default_variant},
+  -- Clear the editor background; nil follows the variant (translucent variants are transparent).
+  transparent = nil,
+  -- Per-role overrides merged into the theme's styles, e.g. {{ comment = {{ italic = false }} }}.
+  styles = {{}},
+  -- Set an integration to false to skip its groups, e.g. {{ telescope = false }}.
+  plugins = {{}},
+  -- function(groups, colors): edit the highlight table before it is applied.
+  on_highlights = nil,
+}}
+
+function M.setup(opts)
+  M.config = vim.tbl_deep_extend('force', M.config, opts or {{}})
+end
+
+--- Apply `variant`, or `M.config.variant` when omitted, as the current colorscheme.
+function M.load(variant)
+  local opts = vim.deepcopy(M.config)
+  opts.variant = variant or opts.variant
+  local c = require({palette_module})[opts.variant]
+  if not c then
+    error({unknown_variant} .. tostring(opts.variant))
+  end
+  if opts.transparent == nil then opts.transparent = vim.g.{global}_transparent end
+  if opts.transparent == nil then opts.transparent = c.transparent end
+
+  local groups = require({groups_module}).get(c, opts)
+  if opts.on_highlights then opts.on_highlights(groups, c) end
+
+  vim.cmd('highlight clear')
+  if vim.fn.exists('syntax_on') == 1 then vim.cmd('syntax reset') end
+  vim.o.background = 'dark'
+  vim.g.colors_name = c.name
+  for name, val in pairs(groups) do
+    vim.api.nvim_set_hl(0, name, val)
+  end
+  for i, color in ipairs(c.terminal) do
+    vim.g['terminal_color_' .. (i - 1)] = color
+  end
+end
+
+return M
+"#,
+        default_variant = lua_str(default_variant),
+        palette_module = lua_str(&format!("{}.palette", module)),
+        groups_module = lua_str(&format!("{}.groups", module)),
+        unknown_variant = lua_str(&format!("{}: unknown variant ", cfg.meta.name)),
+        global = module.replace('-', "_"),
+    )
+}
+
+/// One variant's entry in `palette.lua`: the `c` colors, resolved `[syntax]`
+/// role styles and the 16 terminal colors.
+fn variant_palette(cfg: &Config, variant: &Variant, palette: &Palette, colors_name: &str, transparent: bool) -> String {
+    let bg = palette.ui.background;
+    let mut out = String::new();
+    let _ = writeln!(out, "  [{}] = {{", lua_str(&variant.name));
+    let _ = writeln!(out, "    name = {},", lua_str(colors_name));
+    let _ = writeln!(out, "    transparent = {},", transparent);
+    for (key, color) in colors(palette) {
+        let _ = writeln!(out, "    {} = '{}',", key, color);
+    }
+    out.push_str("    syntax = {\n");
+    for (role, style) in cfg.syntax.styles(palette) {
+        if style == Style::default() {
+            continue;
+        }
+        let mut attrs = Vec::new();
+        if let Some(c) = style.color { attrs.push(format!("fg = '{}'", c.over(bg))); }
+        if style.bold { attrs.push("bold = true".to_string()); }
+        if style.italic { attrs.push("italic = true".to_string()); }
+        if style.underline { attrs.push("underline = true".to_string()); }
+        let _ = writeln!(out, "      ['{}'] = {{ {} }},", role.name(), attrs.join(", "));
+    }
+    out.push_str("    },\n    terminal = {\n");
+    let ansi = palette.base.ansi.colors();
+    let slots = ansi.iter().map(|(_, c)| c.base).chain(ansi.iter().map(|(_, c)| c.bright));
+    for c in slots {
+        let _ = writeln!(out, "      '{}',", c.over(bg));
+    }
+    out.push_str("    },\n  },\n");
+    out
+}
+
+/// `lua/<slug>/groups.lua`: every highlight group in terms of a palette entry and the setup options.
+fn groups_module(plugins: &[&str]) -> String {
+    let mut out = String::from("-- Generated by colorloom\nlocal M = {}\n\n-- Highlight groups styled by each [syntax] role in theme.toml.\nM.roles = {\n");
+    for role in Role::ALL {
+        let names: Vec<_> = groups(role).iter().map(|g| format!("'{}'", g)).collect();
+        let _ = writeln!(out, "  ['{}'] = {{ {} }},", role.name(), names.join(", "));
+    }
+    out.push_str("}\n\n--- Highlight definitions for palette entry `c`, keyed by group name.\nfunction M.get(c, opts)\n  local g = {}\n  local function hl(name, val) g[name] = val end\n");
+    out.push_str(&indent(UI_GROUPS));
+    out.push_str(&indent(TRANSPARENT_GROUPS));
+    out.push_str(&indent(ROLE_GROUPS));
+    out.push_str(&indent(SYNTAX_GROUPS));
+    for (plugin, groups) in PLUGINS.iter().filter(|(p, _)| plugins.contains(p)) {
+        let _ = write!(out, "\n  -- Plugin: {}\n  if opts.plugins['{}'] ~= false then\n{}  end\n", plugin, plugin, indent(&indent(groups)));
+    }
+    out.push_str("  return g\nend\n\nreturn M\n");
+    out
+}
+
+/// `s` as a single-quoted Lua string literal.
+fn lua_str(s: &str) -> String {
+    let mut out = String::from("'");
+    for ch in s.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_ascii_control() => {
+                let _ = write!(out, "\\{:03}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('\'');
+    out
+}
+
+fn indent(lua: &str) -> String {
+    lua.lines().map(|l| if l.is_empty() { "\n".to_string() } else { format!("  {}\n", l) }).collect()
+}
+
+/// lualine theme module; mode colors follow the ANSI accents.
+fn lualine(module: &str, variant: &str) -> String {
+    format!(
+        r#"-- Generated by colorloom
+local c = require({})[{}]
+return {{
+  normal = {{
+    a = {{ fg = c.bg, bg = c.blue, gui = 'bold' }},
+    b = {{ fg = c.fg, bg = c.bg_elevated }},
+    c = {{ fg = c.fg_muted, bg = c.bg_alt }},
+  }},
+  insert = {{ a = {{ fg = c.bg, bg = c.green, gui = 'bold' }} }},
+  visual = {{ a = {{ fg = c.bg, bg = c.magenta, gui = 'bold' }} }},
+  replace = {{ a = {{ fg = c.bg, bg = c.red, gui = 'bold' }} }},
+  command = {{ a = {{ fg = c.bg, bg = c.yellow, gui = 'bold' }} }},
+  terminal = {{ a = {{ fg = c.bg, bg = c.cyan, gui = 'bold' }} }},
+  inactive = {{
+    a = {{ fg = c.fg_dim, bg = c.bg_alt }},
+    b = {{ fg = c.fg_dim, bg = c.bg_alt }},
+    c = {{ fg = c.fg_dim, bg = c.bg_alt }},
+  }},
+}}
+"#,
+        lua_str(&format!("{}.palette", module)),
+        lua_str(variant)
+    )
+}
+
+/// The `c` table every highlight group draws from. Neovim only accepts opaque
+/// `#rrggbb`, so everything is composited onto the (already flattened) background.
+fn colors(palette: &Palette) -> Vec<(&'static str, Color)> {
+    let (ui, ansi, border) = (&palette.ui, &palette.base.ansi, &palette.border);
+    let bg = ui.background;
+    // Diff and diagnostic backgrounds: the accent color faintly tinting the editor background.
+    let tint = |c: Color, amount: f32| c.apply_alpha(amount).over(bg);
+    let colors = [
+        ("bg", bg),
+        ("bg_alt", ui.background_alt),
+        ("bg_elevated", ui.background_elevated),
+        ("fg", ui.foreground),
+        ("fg_muted", ui.foreground_muted),
+        ("fg_dim", ui.foreground_dim),
+        ("selection", ui.selection),
+        ("selection_fg", ui.selection_foreground),
+        ("cursor", ui.cursor),
+        ("cursor_fg", ui.cursor_text),
+        ("line", ui.line_highlight),
+        ("border", border.border),
+        ("border_variant", border.border_variant),
+        ("border_focused", border.border_focused),
+        ("red", ansi.red.base),
+        ("green", ansi.green.base),
+        ("yellow", ansi.yellow.base),
+        ("blue", ansi.blue.base),
+        ("magenta", ansi.magenta.base),
+        ("cyan", ansi.cyan.base),
+        ("purple", palette.syntax.lavender),
+        ("teal", palette.syntax.teal),
+        ("dark_blue", palette.syntax.blue_green),
+        ("error", ansi.red.base),
+        ("warn", ansi.yellow.base),
+        ("info", ansi.blue.base),
+        ("hint", ansi.cyan.base),
+        ("ok", ansi.green.base),
+        ("error_bg", tint(ansi.red.base, 0.1)),
+        ("warn_bg", tint(ansi.yellow.base, 0.1)),
+        ("info_bg", tint(ansi.blue.base, 0.1)),
+        ("hint_bg", tint(ansi.cyan.base, 0.1)),
+        ("ok_bg", tint(ansi.green.base, 0.1)),
+        ("diff_add", tint(ansi.green.base, 0.15)),
+        ("diff_change", tint(ansi.blue.base, 0.15)),
+        ("diff_delete", tint(ansi.red.base, 0.15)),
+        ("diff_text", tint(ansi.blue.base, 0.3)),
+    ];
+    colors.into_iter().map(|(k, c)| (k, c.over(bg))).collect()
+}
+
+/// Plugin integrations named in `[[targets]].plugins`, all of them by default.
+fn plugins(target: &config::Target) -> Result<Vec<&'static str>> {
+    let available: Vec<_> = PLUGINS.iter().map(|(name, _)| *name).chain(["lualine"]).collect();
+    let Some(requested) = &target.plugins else { return Ok(available) };
+    requested
+        .iter()
+        .map(|p| {
+            available
+                .iter()
+                .find(|a| *a == p)
+                .copied()
+                .ok_or_else(|| anyhow!("unknown Neovim plugin integration `{}` (available: {})", p, available.join(", ")))
+        })
+        .collect()
+}
+
+/// Highlight groups for plugins, keyed by the name used in `[[targets]].plugins`.
+const PLUGINS: &[(&str, &str)] = &[
+    ("telescope", r#"hl('TelescopeNormal', { fg = c.fg, bg = c.bg_alt })
+hl('TelescopeBorder', { fg = c.border, bg = c.bg_alt })
+hl('TelescopeTitle', { fg = c.bg, bg = c.blue, bold = true })
+hl('TelescopePromptNormal', { fg = c.fg, bg = c.bg_elevated })
+hl('TelescopePromptBorder', { fg = c.border, bg = c.bg_elevated })
+hl('TelescopePromptTitle', { fg = c.bg, bg = c.cyan, bold = true })
+hl('TelescopePromptPrefix', { fg = c.cyan, bg = c.bg_elevated })
+hl('TelescopePreviewTitle', { fg = c.bg, bg = c.green, bold = true })
+hl('TelescopeResultsTitle', { fg = c.bg, bg = c.blue, bold = true })
+hl('TelescopeSelection', { fg = c.selection_fg, bg = c.selection })
+hl('TelescopeSelectionCaret', { fg = c.cyan, bg = c.selection })
+hl('TelescopeMultiSelection', { fg = c.magenta })
+hl('TelescopeMatching', { fg = c.yellow, bold = true })
+"#),
+    ("gitsigns", r#"hl('GitSignsAdd', { fg = c.green })
+hl('GitSignsChange', { fg = c.blue })
+hl('GitSignsDelete', { fg = c.red })
+hl('GitSignsAddNr', { link = 'GitSignsAdd' })
+hl('GitSignsChangeNr', { link = 'GitSignsChange' })
+hl('GitSignsDeleteNr', { link = 'GitSignsDelete' })
+hl('GitSignsAddLn', { bg = c.diff_add })
+hl('GitSignsChangeLn', { bg = c.diff_change })
+hl('GitSignsDeleteLn', { bg = c.diff_delete })
+hl('GitSignsAddInline', { bg = c.diff_add })
+hl('GitSignsChangeInline', { bg = c.diff_text })
+hl('GitSignsDeleteInline', { bg = c.diff_delete })
+hl('GitSignsCurrentLineBlame', { fg = c.fg_dim, italic = true })
+"#),
+    ("nvim-cmp", r#"hl('CmpItemAbbr', { fg = c.fg })
+hl('CmpItemAbbrDeprecated', { fg = c.fg_dim, strikethrough = true })
+hl('CmpItemAbbrMatch', { fg = c.cyan, bold = true })
+hl('CmpItemAbbrMatchFuzzy', { fg = c.cyan })
+hl('CmpItemMenu', { fg = c.fg_muted })
+hl('CmpItemKind', { fg = c.teal })
+hl('CmpItemKindText', { fg = c.fg_muted })
+hl('CmpItemKindSnippet', { fg = c.purple })
+hl('CmpItemKindKeyword', { link = '@keyword' })
+hl('CmpItemKindFunction', { link = '@function' })
+hl('CmpItemKindMethod', { link = '@function.method' })
+hl('CmpItemKindConstructor', { link = '@constructor' })
+hl('CmpItemKindVariable', { link = '@variable' })
+hl('CmpItemKindField', { link = '@property' })
+hl('CmpItemKindProperty', { link = '@property' })
+hl('CmpItemKindClass', { link = '@type' })
+hl('CmpItemKindStruct', { link = '@type' })
+hl('CmpItemKindInterface', { link = '@type' })
+hl('CmpItemKindEnum', { link = '@type' })
+hl('CmpItemKindEnumMember', { link = '@constant' })
+hl('CmpItemKindConstant', { link = '@constant' })
+hl('CmpItemKindModule', { link = '@module' })
+hl('CmpItemKindOperator', { link = '@operator' })
+"#),
+    ("which-key", r#"hl('WhichKey', { fg = c.cyan })
+hl('WhichKeyGroup', { fg = c.blue })
+hl('WhichKeyDesc', { fg = c.fg })
+hl('WhichKeySeparator', { fg = c.fg_dim })
+hl('WhichKeyValue', { fg = c.fg_muted })
+hl('WhichKeyNormal', { link = 'NormalFloat' })
+hl('WhichKeyBorder', { link = 'FloatBorder' })
+"#),
+    ("nvim-tree", r#"hl('NvimTreeNormal', { fg = c.fg, bg = c.bg_alt })
+hl('NvimTreeNormalNC', { link = 'NvimTreeNormal' })
+hl('NvimTreeWinSeparator', { fg = c.border, bg = c.bg_alt })
+hl('NvimTreeCursorLine', { bg = c.line })
+hl('NvimTreeRootFolder', { fg = c.blue, bold = true })
+hl('NvimTreeFolderName', { fg = c.blue })
+hl('NvimTreeOpenedFolderName', { fg = c.blue, bold = true })
+hl('NvimTreeEmptyFolderName', { fg = c.fg_dim })
+hl('NvimTreeFolderIcon', { fg = c.blue })
+hl('NvimTreeIndentMarker', { fg = c.border })
+hl('NvimTreeSpecialFile', { fg = c.magenta, underline = true })
+hl('NvimTreeImageFile', { fg = c.purple })
+hl('NvimTreeGitDirty', { fg = c.yellow })
+hl('NvimTreeGitNew', { fg = c.green })
+hl('NvimTreeGitDeleted', { fg = c.red })
+hl('NvimTreeGitStaged', { fg = c.cyan })
+"#),
+];
+
+/// Editor UI, diagnostics and LSP groups, all in terms of the `c` table.
+const UI_GROUPS: &str = r#"
+-- Editor
+hl('Normal', { fg = c.fg, bg = c.bg })
+hl('NormalNC', { fg = c.fg, bg = c.bg })
+hl('NormalFloat', { fg = c.fg, bg = c.bg_elevated })
+hl('FloatBorder', { fg = c.border, bg = c.bg_elevated })
+hl('FloatTitle', { fg = c.fg, bg = c.bg_elevated, bold = true })
+hl('Cursor', { fg = c.cursor_fg, bg = c.cursor })
+hl('lCursor', { link = 'Cursor' })
+hl('CursorIM', { link = 'Cursor' })
+hl('TermCursor', { link = 'Cursor' })
+hl('CursorLine', { bg = c.line })
+hl('CursorColumn', { bg = c.line })
+hl('ColorColumn', { bg = c.bg_alt })
+hl('CursorLineNr', { fg = c.fg, bold = true })
+hl('LineNr', { fg = c.fg_dim })
+hl('LineNrAbove', { link = 'LineNr' })
+hl('LineNrBelow', { link = 'LineNr' })
+hl('SignColumn', { fg = c.fg_dim, bg = c.bg })
+hl('FoldColumn', { fg = c.fg_dim, bg = c.bg })
+hl('Folded', { fg = c.fg_muted, bg = c.bg_alt })
+hl('WinSeparator', { fg = c.border })
+hl('VertSplit', { link = 'WinSeparator' })
+hl('EndOfBuffer', { fg = c.bg })
+hl('NonText', { fg = c.fg_dim })
+hl('Whitespace', { fg = c.border_variant })
+hl('SpecialKey', { fg = c.fg_dim })
+hl('Conceal', { fg = c.fg_dim })
+hl('Visual', { bg = c.selection, fg = c.selection_fg })
+hl('VisualNOS', { link = 'Visual' })
+hl('Search', { fg = c.bg, bg = c.yellow })
+hl('IncSearch', { fg = c.bg, bg = c.cyan })
+hl('CurSearch', { link = 'IncSearch' })
+hl('Substitute', { fg = c.bg, bg = c.red })
+hl('MatchParen', { fg = c.cyan, bold = true, underline = true })
+hl('QuickFixLine', { bg = c.selection })
+hl('Directory', { fg = c.blue })
+hl('Title', { fg = c.blue, bold = true })
+hl('Question', { fg = c.green })
+hl('MoreMsg', { fg = c.green })
+hl('ModeMsg', { fg = c.fg, bold = true })
+hl('MsgArea', { fg = c.fg })
+hl('ErrorMsg', { fg = c.error })
+hl('WarningMsg', { fg = c.warn })
+hl('SpellBad', { sp = c.error, undercurl = true })
+hl('SpellCap', { sp = c.warn, undercurl = true })
+hl('SpellLocal', { sp = c.info, undercurl = true })
+hl('SpellRare', { sp = c.magenta, undercurl = true })
+
+-- Status, tab and window bars
+hl('StatusLine', { fg = c.fg, bg = c.bg_elevated })
+hl('StatusLineNC', { fg = c.fg_dim, bg = c.bg_alt })
+hl('TabLine', { fg = c.fg_muted, bg = c.bg_alt })
+hl('TabLineFill', { bg = c.bg_alt })
+hl('TabLineSel', { fg = c.fg, bg = c.bg, bold = true })
+hl('WinBar', { fg = c.fg_muted, bg = c.bg })
+hl('WinBarNC', { fg = c.fg_dim, bg = c.bg })
+
+-- Popup menu
+hl('Pmenu', { fg = c.fg, bg = c.bg_elevated })
+hl('PmenuSel', { fg = c.selection_fg, bg = c.selection })
+hl('PmenuKind', { fg = c.teal, bg = c.bg_elevated })
+hl('PmenuKindSel', { fg = c.teal, bg = c.selection })
+hl('PmenuExtra', { fg = c.fg_muted, bg = c.bg_elevated })
+hl('PmenuExtraSel', { fg = c.fg_muted, bg = c.selection })
+hl('PmenuSbar', { bg = c.bg_alt })
+hl('PmenuThumb', { bg = c.border })
+hl('WildMenu', { link = 'PmenuSel' })
+
+-- Diff
+hl('DiffAdd', { bg = c.diff_add })
+hl('DiffChange', { bg = c.diff_change })
+hl('DiffDelete', { fg = c.red, bg = c.diff_delete })
+hl('DiffText', { bg = c.diff_text })
+hl('Added', { fg = c.green })
+hl('Changed', { fg = c.blue })
+hl('Removed', { fg = c.red })
+
+-- Diagnostics
+hl('DiagnosticError', { fg = c.error })
+hl('DiagnosticWarn', { fg = c.warn })
+hl('DiagnosticInfo', { fg = c.info })
+hl('DiagnosticHint', { fg = c.hint })
+hl('DiagnosticOk', { fg = c.ok })
+hl('DiagnosticUnderlineError', { sp = c.error, undercurl = true })
+hl('DiagnosticUnderlineWarn', { sp = c.warn, undercurl = true })
+hl('DiagnosticUnderlineInfo', { sp = c.info, undercurl = true })
+hl('DiagnosticUnderlineHint', { sp = c.hint, undercurl = true })
+hl('DiagnosticUnderlineOk', { sp = c.ok, undercurl = true })
+hl('DiagnosticVirtualTextError', { fg = c.error, bg = c.error_bg })
+hl('DiagnosticVirtualTextWarn', { fg = c.warn, bg = c.warn_bg })
+hl('DiagnosticVirtualTextInfo', { fg = c.info, bg = c.info_bg })
+hl('DiagnosticVirtualTextHint', { fg = c.hint, bg = c.hint_bg })
+hl('DiagnosticVirtualTextOk', { fg = c.ok, bg = c.ok_bg })
+hl('DiagnosticSignError', { fg = c.error })
+hl('DiagnosticSignWarn', { fg = c.warn })
+hl('DiagnosticSignInfo', { fg = c.info })
+hl('DiagnosticSignHint', { fg = c.hint })
+hl('DiagnosticSignOk', { fg = c.ok })
+hl('DiagnosticFloatingError', { link = 'DiagnosticError' })
+hl('DiagnosticFloatingWarn', { link = 'DiagnosticWarn' })
+hl('DiagnosticFloatingInfo', { link = 'DiagnosticInfo' })
+hl('DiagnosticFloatingHint', { link = 'DiagnosticHint' })
+hl('DiagnosticFloatingOk', { link = 'DiagnosticOk' })
+hl('DiagnosticUnnecessary', { fg = c.fg_dim })
+hl('DiagnosticDeprecated', { strikethrough = true })
+
+-- LSP
+hl('LspReferenceText', { bg = c.line })
+hl('LspReferenceRead', { bg = c.line })
+hl('LspReferenceWrite', { bg = c.line, underline = true })
+hl('LspInlayHint', { fg = c.fg_dim, bg = c.bg_alt, italic = true })
+hl('LspCodeLens', { fg = c.fg_dim })
+hl('LspSignatureActiveParameter', { bold = true, underline = true })
+"#;
+
+/// With `transparent`, the base groups drop their background so the terminal's shows through.
+const TRANSPARENT_GROUPS: &str = r#"
+if opts.transparent then
+  hl('Normal', { fg = c.fg })
+  hl('NormalNC', { fg = c.fg })
+  hl('SignColumn', { fg = c.fg_dim })
+  hl('FoldColumn', { fg = c.fg_dim })
+end
+"#;
+
+/// Applies each role's style from the palette, with `opts.styles` merged over it, to its groups.
+const ROLE_GROUPS: &str = r#"
+-- Syntax
+for role, names in pairs(M.roles) do
+  if c.syntax[role] or opts.styles[role] then
+    for _, name in ipairs(names) do
+      hl(name, vim.tbl_extend('force', c.syntax[role] or {}, opts.styles[role] or {}))
+    end
+  end
+end
+"#;
+
+/// Syntax groups that aren't semantic roles: markup, diff and comment notes,
+/// plus links that keep related captures in step with their role.
+const SYNTAX_GROUPS: &str = r#"hl('Special', { fg = c.cyan })
+hl('Error', { fg = c.error })
+hl('Todo', { fg = c.bg, bg = c.yellow, bold = true })
+hl('Underlined', { underline = true })
+hl('@variable.builtin', { link = '@keyword' })
+hl('@label', { link = '@keyword' })
+hl('@tag.attribute', { link = '@attribute' })
+hl('@tag.delimiter', { link = '@punctuation' })
+hl('@string.special.url', { fg = c.cyan, underline = true })
+hl('@comment.error', { fg = c.error, bold = true })
+hl('@comment.warning', { fg = c.warn, bold = true })
+hl('@comment.todo', { link = 'Todo' })
+hl('@comment.note', { fg = c.info, bold = true })
+hl('@markup.heading', { fg = c.blue, bold = true })
+hl('@markup.strong', { bold = true })
+hl('@markup.italic', { italic = true })
+hl('@markup.strikethrough', { strikethrough = true })
+hl('@markup.underline', { underline = true })
+hl('@markup.quote', { fg = c.fg_muted, italic = true })
+hl('@markup.math', { fg = c.purple })
+hl('@markup.link', { fg = c.cyan })
+hl('@markup.link.url', { fg = c.cyan, underline = true })
+hl('@markup.raw', { link = '@string' })
+hl('@markup.list', { fg = c.cyan })
+hl('@diff.plus', { fg = c.green })
+hl('@diff.minus', { fg = c.red })
+hl('@diff.delta', { fg = c.blue })
+"#;
+
+/// Highlight groups for each role: the legacy Vim syntax group first, then
+/// Tree-sitter captures, then LSP semantic token types. Unlisted captures fall
+/// back to their parent capture or legacy group.
+fn groups(role: Role) -> &'static [&'static str] {
+    match role {
+        Role::Comment => &["Comment", "@comment", "@lsp.type.comment"],
+        Role::Keyword => &["Keyword", "Statement", "Conditional", "Repeat", "@keyword", "@lsp.type.keyword"],
+        Role::Function => &["Function", "@function", "@function.call", "@lsp.type.function"],
+        Role::Method => &["@function.method", "@function.method.call", "@lsp.type.method"],
+        Role::Type => &["Type", "@type", "@lsp.type.type", "@lsp.type.class", "@lsp.type.struct", "@lsp.type.enum", "@lsp.type.interface", "@lsp.type.typeParameter"],
+        Role::Constructor => &["@constructor"],
+        Role::String => &["String", "@string", "@lsp.type.string"],
+        Role::Escape => &["SpecialChar", "@string.escape"],
+        Role::Regex => &["@string.regexp", "@lsp.type.regexp"],
+        Role::Number => &["Number", "Float", "@number", "@lsp.type.number"],
+        Role::Boolean => &["Boolean", "@boolean"],
+        Role::Constant => &["Constant", "@constant", "@lsp.type.enumMember"],
+        Role::Operator => &["Operator", "@operator", "@lsp.type.operator"],
+        Role::Attribute => &["@attribute", "@lsp.type.decorator"],
+        Role::Variable => &["Identifier", "@variable", "@lsp.type.variable"],
+        Role::Parameter => &["@variable.parameter", "@lsp.type.parameter"],
+        Role::Property => &["@property", "@variable.member", "@lsp.type.property"],
+        Role::Macro => &["Macro", "PreProc", "@function.macro", "@lsp.type.macro"],
+        Role::Namespace => &["@module", "@lsp.type.namespace"],
+        Role::Punctuation => &["Delimiter", "@punctuation"],
+        Role::Tag => &["Tag", "@tag"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lua_strings_are_escaped() {
+        assert_eq!(lua_str("it's a \\ test\n\t1"), r"'it\'s a \\ test\n\0091'");
+        let mut cfg = crate::targets::test_config();
+        cfg.meta.name = "Don't Panic".to_string();
+        assert!(init_module(&cfg, "dont-panic", "base").contains(r"error('Don\'t Panic: unknown variant ' .. tostring(opts.variant))"));
+    }
+}
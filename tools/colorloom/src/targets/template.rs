@@ -0,0 +1,186 @@
+use super::{Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Palette, Variant};
+use anyhow::{anyhow, Context, Result};
+use minijinja::{context, Environment, Error, ErrorKind, UndefinedBehavior, Value};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Renders a user-supplied Jinja-style template once per variant.
+///
+/// Templates see `meta`, `variant`, `ui` (with variant alpha and overrides
+/// applied), `ansi`, `syntax`, `border`, the raw `palette`, and `roles`: each
+/// `[syntax]` role's resolved `color` (or none), `bold`, `italic` and `underline`. Colors are
+/// `#rrggbb`/`#rrggbbaa` strings; see [`environment`] for the color filters.
+pub struct Template;
+
+impl Target for Template {
+    fn id(&self) -> &'static str {
+        "template"
+    }
+
+    fn description(&self) -> &'static str {
+        "User-supplied template rendered per variant"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: true,
+            variants: true,
+            alpha: true,
+            blur: true,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String {
+        let suffix = variant.map(|v| format!("-{}", v.name)).unwrap_or_default();
+        format!("{}{}", cfg.meta.slug(), suffix)
+    }
+
+    fn file_name(&self, cfg: &Config, target: &config::Target, variant: Option<&Variant>) -> String {
+        if let Some(name) = variant.and_then(|v| target.out_names.as_ref()?.get(&v.name)) {
+            return name.clone();
+        }
+        let ext = target.template.as_deref().map(output_extension).unwrap_or_default();
+        format!("{}{}", self.default_file_name(cfg, variant), ext)
+    }
+
+    fn inputs(&self, target: &config::Target) -> Vec<PathBuf> {
+        target.template.iter().map(PathBuf::from).collect()
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, root: &Path) -> Result<Vec<Output>> {
+        let template_path = target
+            .template
+            .as_deref()
+            .ok_or_else(|| anyhow!("template target {} has no `template` file", target.id))?;
+        let source = fs::read_to_string(root.join(template_path)).with_context(|| format!("reading {}", template_path))?;
+        let env = environment();
+        let tmpl = env.template_from_named_str(template_path, &source)?;
+        let dir = Path::new(&target.path);
+        let mut outputs = Vec::new();
+        for v in self.variants(cfg) {
+            let out = tmpl
+                .render(template_context(cfg, v, &self.palette(cfg, target, v)))
+                .with_context(|| format!("rendering {} for variant {}", template_path, v.name))?;
+            outputs.push(Output::new(dir.join(self.file_name(cfg, target, Some(v))), out));
+        }
+        Ok(outputs)
+    }
+}
+
+/// Template environment with the color filters registered:
+///
+/// - `strip_alpha`: `#rrggbbaa` -> `#rrggbb`
+/// - `with_alpha(a)`: replace alpha with `a` in `0.0..=1.0`
+/// - `over(backdrop)`: composite a translucent color onto `backdrop`
+/// - `hex`: normalise to `#rrggbb` or `#rrggbbaa`
+/// - `rgb` / `rgba`: `rgb(r, g, b)` / `rgba(r, g, b, a)`
+/// - `hsl`: `hsl(h, s%, l%)`
+/// - `lighten(amount)` / `darken(amount)`: shift HSL lightness by `amount` in `0.0..=1.0`
+fn environment() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_keep_trailing_newline(true);
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.add_filter("strip_alpha", |c: String| -> Result<String, Error> { Ok(parse(&c)?.strip_alpha().to_string()) });
+    env.add_filter("with_alpha", |c: String, a: f32| -> Result<String, Error> { Ok(parse(&c)?.apply_alpha(a).to_string()) });
+    env.add_filter("over", |c: String, backdrop: String| -> Result<String, Error> { Ok(parse(&c)?.over(parse(&backdrop)?).to_string()) });
+    env.add_filter("hex", |c: String| -> Result<String, Error> { Ok(parse(&c)?.to_string()) });
+    env.add_filter("rgb", |c: String| -> Result<String, Error> {
+        let c = parse(&c)?;
+        Ok(format!("rgb({}, {}, {})", c.r, c.g, c.b))
+    });
+    env.add_filter("rgba", |c: String| -> Result<String, Error> {
+        let c = parse(&c)?;
+        Ok(format!("rgba({}, {}, {}, {:.2})", c.r, c.g, c.b, c.a as f32 / 255.0))
+    });
+    env.add_filter("hsl", |c: String| -> Result<String, Error> {
+        let (h, s, l) = parse(&c)?.to_hsl();
+        Ok(format!("hsl({:.0}, {:.0}%, {:.0}%)", h, s * 100.0, l * 100.0))
+    });
+    env.add_filter("lighten", |c: String, amount: f32| -> Result<String, Error> { Ok(parse(&c)?.lighten(amount).to_string()) });
+    env.add_filter("darken", |c: String, amount: f32| -> Result<String, Error> { Ok(parse(&c)?.darken(amount).to_string()) });
+    env
+}
+
+/// Variables exposed to a template for one variant; `palette` already has the
+/// variant's overrides applied.
+fn template_context(cfg: &Config, variant: &Variant, palette: &Palette) -> Value {
+    context! {
+        meta => context! { slug => cfg.meta.slug(), ..Value::from_serialize(&cfg.meta) },
+        variant => context! {
+            name => &variant.name,
+            // Round-trip through the TOML text so 0.8 renders as 0.8, not 0.800000011920929.
+            alpha => variant.alpha.map(|a| a.to_string().parse::<f64>().unwrap_or(a as f64)),
+            blur_radius => variant.blur_radius,
+            backdrop => variant.backdrop(),
+        },
+        ui => &palette.ui,
+        ansi => &palette.base.ansi,
+        syntax => &palette.syntax,
+        roles => cfg.syntax.styles(palette).into_iter().map(|(r, s)| (r.name(), s)).collect::<BTreeMap<_, _>>(),
+        border => &palette.border,
+        palette => palette,
+    }
+}
+
+fn parse(s: &str) -> Result<Color, Error> {
+    s.parse().map_err(|e| Error::new(ErrorKind::InvalidOperation, format!("{}", e)))
+}
+
+/// Extension of the rendered file: the template's own extension once a
+/// template suffix (`.j2`, `.jinja`, `.jinja2`, `.tmpl`) is stripped.
+fn output_extension(template: &str) -> String {
+    let name = Path::new(template).file_name().and_then(|n| n.to_str()).unwrap_or(template);
+    let stem = [".j2", ".jinja2", ".jinja", ".tmpl"]
+        .iter()
+        .find_map(|suffix| name.strip_suffix(suffix))
+        .unwrap_or(name);
+    Path::new(stem).extension().and_then(|e| e.to_str()).map(|e| format!(".{}", e)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(source: &str) -> Result<String, Error> {
+        environment().render_str(source, context! { red => "#FF000080" })
+    }
+
+    #[test]
+    fn color_filters() {
+        for (source, expected) in [
+            ("{{ red | strip_alpha }}", "#ff0000"),
+            ("{{ red | with_alpha(1.0) }}", "#ff0000"),
+            ("{{ red | strip_alpha | with_alpha(0.25) }}", "#ff000040"),
+            ("{{ red | over('#000000') }}", "#800000"),
+            ("{{ red | hex }}", "#ff000080"),
+            ("{{ red | rgb }}", "rgb(255, 0, 0)"),
+            ("{{ red | rgba }}", "rgba(255, 0, 0, 0.50)"),
+            ("{{ red | hsl }}", "hsl(0, 100%, 50%)"),
+            ("{{ red | strip_alpha | lighten(0.1) }}", "#ff3333"),
+            ("{{ red | strip_alpha | darken(0.1) }}", "#cc0000"),
+        ] {
+            assert_eq!(render(source).unwrap(), expected, "{}", source);
+        }
+        assert!(render("{{ 'not a color' | hex }}").is_err());
+    }
+
+    #[test]
+    fn output_extension_strips_template_suffixes() {
+        for template in ["theme.conf.j2", "theme.conf.jinja", "theme.conf.jinja2", "theme.conf.tmpl", "templates/theme.conf"] {
+            assert_eq!(output_extension(template), ".conf", "{}", template);
+        }
+        assert_eq!(output_extension("theme.j2"), "");
+    }
+
+    #[test]
+    fn undefined_variables_are_errors() {
+        let err = render("{{ missing }}").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UndefinedError);
+        assert!(render("{{ ui.background }}").is_err());
+    }
+}
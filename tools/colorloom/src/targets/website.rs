@@ -0,0 +1,67 @@
+use super::{Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Variant};
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub struct Website;
+
+impl Target for Website {
+    fn id(&self) -> &'static str {
+        "website"
+    }
+
+    fn description(&self) -> &'static str {
+        "Palette JSON for the showcase website"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: false,
+            variants: false,
+            alpha: false,
+            blur: false,
+        }
+    }
+
+    fn default_file_name(&self, _cfg: &Config, _variant: Option<&Variant>) -> String {
+        "palette.json".to_string()
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, _root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        // The page has no variants; translucent colors are shown as they look
+        // over the default black backdrop.
+        let palette = cfg.palette.flatten(Color::rgb(0, 0, 0));
+        // Build arrays matching the website component expectations
+        let colors = vec![
+            json!({"name":"Red","base":palette.base.ansi.red.base,"bright":palette.base.ansi.red.bright,"dim":palette.base.ansi.red.dim,"usage":"Errors, deletions, keywords"}),
+            json!({"name":"Green","base":palette.base.ansi.green.base,"bright":palette.base.ansi.green.bright,"dim":palette.base.ansi.green.dim,"usage":"Success, additions"}),
+            json!({"name":"Yellow","base":palette.base.ansi.yellow.base,"bright":palette.base.ansi.yellow.bright,"dim":palette.base.ansi.yellow.dim,"usage":"Warnings, modifications"}),
+            json!({"name":"Blue","base":palette.base.ansi.blue.base,"bright":palette.base.ansi.blue.bright,"dim":palette.base.ansi.blue.dim,"usage":"Info, titles, headings"}),
+            json!({"name":"Magenta","base":palette.base.ansi.magenta.base,"bright":palette.base.ansi.magenta.bright,"dim":palette.base.ansi.magenta.dim,"usage":"Attributes, emphasis, operators"}),
+            json!({"name":"Cyan","base":palette.base.ansi.cyan.base,"bright":palette.base.ansi.cyan.bright,"dim":palette.base.ansi.cyan.dim,"usage":"Focus borders"}),
+        ];
+        let syntax_colors = vec![
+            json!({"name":"Cyan Teal","hex":palette.syntax.teal,"usage":"Functions, methods, strings"}),
+            json!({"name":"Blue Green","hex":palette.syntax.blue_green,"usage":"Keywords, types, constructors"}),
+            json!({"name":"Lavender","hex":palette.syntax.lavender,"usage":"Numbers, constants, inline code"}),
+            json!({"name":"Gray","hex":palette.syntax.gray,"usage":"Comments"}),
+        ];
+        let background_colors = vec![
+            json!({"name":"Background","hex":palette.ui.background,"usage":"Deep purple-black editor background"}),
+            json!({"name":"Background Alt","hex":palette.ui.background_alt,"usage":"Sidebar, panels, inactive tabs"}),
+            json!({"name":"Foreground","hex":palette.ui.foreground,"usage":"Soft white text","textColor":"#000"}),
+        ];
+
+        let obj = json!({
+            "meta": {"name": cfg.meta.name, "author": cfg.meta.author},
+            "colors": colors,
+            "syntaxColors": syntax_colors,
+            "backgroundColors": background_colors
+        });
+        let file = self.file_name(cfg, target, None);
+        Ok(vec![Output::new(dir.join(file), serde_json::to_string_pretty(&obj)?)])
+    }
+}
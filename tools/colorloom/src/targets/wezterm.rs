@@ -0,0 +1,94 @@
+use super::{Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Variant};
+use anyhow::Result;
+use std::{fmt::Write, path::Path};
+
+pub struct Wezterm;
+
+impl Target for Wezterm {
+    fn id(&self) -> &'static str {
+        "wezterm"
+    }
+
+    fn description(&self) -> &'static str {
+        "WezTerm color scheme (TOML)"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: true,
+            variants: true,
+            alpha: false,
+            // Blur is a wezterm.lua window setting; schemes only note it in a comment.
+            blur: false,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, variant: Option<&Variant>) -> String {
+        let suffix = variant.map(|v| format!("-{}", v.name)).unwrap_or_default();
+        format!("{}{}.toml", cfg.meta.slug(), suffix)
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, _root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        let mut outputs = Vec::new();
+        for v in self.variants(cfg) {
+            let background = self.window_background(cfg, target, v);
+            let palette = self.palette(cfg, target, v);
+            let (ui, ansi, border) = (&palette.ui, &palette.base.ansi, &palette.border);
+            let title = self.variant_title(cfg, v);
+            let mut out = format!("# {} for WezTerm\n", title);
+            // Color schemes can't carry window settings; these go in wezterm.lua.
+            if !background.is_opaque() || v.blur_radius.is_some() {
+                out.push_str("# Pair with these settings in wezterm.lua:\n");
+                if !background.is_opaque() {
+                    let _ = writeln!(out, "#   config.window_background_opacity = {:.2}", background.a as f32 / 255.0);
+                }
+                if let Some(radius) = v.blur_radius {
+                    let _ = writeln!(out, "#   config.macos_window_background_blur = {}", radius);
+                }
+            }
+            section(&mut out, "colors", &[
+                ("foreground", ui.foreground),
+                ("background", background.strip_alpha()),
+                ("cursor_bg", ui.cursor),
+                ("cursor_fg", ui.cursor_text),
+                ("cursor_border", ui.cursor),
+                ("selection_fg", ui.selection_foreground),
+                ("selection_bg", ui.selection),
+                ("scrollbar_thumb", border.border),
+                ("split", border.border),
+                ("visual_bell", ansi.yellow.base),
+            ]);
+            let colors = ansi.colors();
+            let list = |pick: fn(&config::ColorVariant) -> Color| {
+                colors.iter().map(|(_, c)| format!("\"{}\"", pick(c))).collect::<Vec<_>>().join(", ")
+            };
+            let _ = writeln!(out, "ansi = [{}]", list(|c| c.base));
+            let _ = writeln!(out, "brights = [{}]", list(|c| c.bright));
+            section(&mut out, "colors.tab_bar", &[("background", ui.background_alt), ("inactive_tab_edge", border.border_variant)]);
+            tab(&mut out, "active_tab", ui.background_elevated, ui.foreground);
+            tab(&mut out, "inactive_tab", ui.background_alt, ui.foreground_dim);
+            tab(&mut out, "inactive_tab_hover", ui.background_elevated, ui.foreground_muted);
+            tab(&mut out, "new_tab", ui.background_alt, ui.foreground_dim);
+            tab(&mut out, "new_tab_hover", ui.background_elevated, ui.foreground);
+            out.push_str("\n[metadata]\n");
+            let _ = writeln!(out, "name = {:?}", title);
+            if let Some(author) = &cfg.meta.author { let _ = writeln!(out, "author = {:?}", author); }
+            outputs.push(Output::new(dir.join(self.file_name(cfg, target, Some(v))), out));
+        }
+        Ok(outputs)
+    }
+}
+
+fn section(out: &mut String, name: &str, entries: &[(&str, Color)]) {
+    let _ = writeln!(out, "\n[{}]", name);
+    for (key, color) in entries {
+        let _ = writeln!(out, "{} = \"{}\"", key, color);
+    }
+}
+
+fn tab(out: &mut String, name: &str, bg: Color, fg: Color) {
+    section(out, &format!("colors.tab_bar.{}", name), &[("bg_color", bg), ("fg_color", fg)]);
+}
@@ -0,0 +1,87 @@
+use super::{capitalize, Capabilities, Output, Target};
+use crate::config::{self, Config, Variant};
+use anyhow::Result;
+use serde_json::{json, Map, Value};
+use std::path::Path;
+
+pub struct WindowsTerminal;
+
+impl Target for WindowsTerminal {
+    fn id(&self) -> &'static str {
+        "windows-terminal"
+    }
+
+    fn description(&self) -> &'static str {
+        "Windows Terminal `schemes` JSON fragment"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: false,
+            variants: true,
+            alpha: false,
+            blur: false,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, _variant: Option<&Variant>) -> String {
+        format!("{}.json", cfg.meta.slug())
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, _root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        let mut schemes = Vec::new();
+        for v in self.variants(cfg) {
+            let palette = self.palette(cfg, target, v);
+            let ui = &palette.ui;
+            let name = self.variant_title(cfg, v);
+            let mut scheme = Map::new();
+            scheme.insert("name".into(), json!(name));
+            scheme.insert("background".into(), json!(ui.background));
+            scheme.insert("foreground".into(), json!(ui.foreground));
+            scheme.insert("cursorColor".into(), json!(ui.cursor));
+            scheme.insert("selectionBackground".into(), json!(ui.selection));
+            // Windows Terminal calls ANSI magenta `purple`.
+            for (name, c) in palette.base.ansi.colors() {
+                let key = if name == "magenta" { "purple" } else { name };
+                scheme.insert(key.to_string(), json!(c.base));
+                scheme.insert(format!("bright{}", capitalize(key)), json!(c.bright));
+            }
+            schemes.push(Value::Object(scheme));
+        }
+        let file = self.file_name(cfg, target, None);
+        let doc = json!({ "schemes": schemes });
+        Ok(vec![Output::new(dir.join(file), serde_json::to_string_pretty(&doc)? + "\n")])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::targets::test_config;
+
+    #[test]
+    fn schemes_have_every_required_key() {
+        let cfg = test_config();
+        let target = cfg.targets.iter().find(|t| t.kind() == "windows-terminal").unwrap();
+        let outputs = WindowsTerminal.render(&cfg, target, Path::new(".")).unwrap();
+        assert_eq!(outputs.len(), 1);
+        let doc: Value = serde_json::from_str(&outputs[0].contents).unwrap();
+        let schemes = doc["schemes"].as_array().unwrap();
+        assert_eq!(schemes.len(), cfg.variants.len());
+        const REQUIRED: [&str; 21] = [
+            "name", "background", "foreground", "cursorColor", "selectionBackground",
+            "black", "red", "green", "yellow", "blue", "purple", "cyan", "white",
+            "brightBlack", "brightRed", "brightGreen", "brightYellow", "brightBlue", "brightPurple", "brightCyan", "brightWhite",
+        ];
+        for (scheme, v) in schemes.iter().zip(WindowsTerminal.variants(&cfg)) {
+            for key in REQUIRED {
+                assert!(scheme.get(key).is_some_and(Value::is_string), "{} is missing `{}`", scheme["name"], key);
+            }
+            assert!(scheme.get("magenta").is_none() && scheme.get("brightMagenta").is_none());
+            let magenta = &WindowsTerminal.palette(&cfg, target, v).base.ansi.magenta;
+            assert_eq!(scheme["purple"], json!(magenta.base));
+            assert_eq!(scheme["brightPurple"], json!(magenta.bright));
+        }
+    }
+}
@@ -0,0 +1,371 @@
+use super::{Capabilities, Output, Target};
+use crate::color::Color;
+use crate::config::{self, Config, Palette, UiPalette, Variant};
+use crate::schema;
+use crate::syntax::{Role, Style};
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Map, Value};
+use std::{
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+pub struct Zed;
+
+impl Target for Zed {
+    fn id(&self) -> &'static str {
+        "zed"
+    }
+
+    fn description(&self) -> &'static str {
+        "Zed theme family JSON"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            per_variant_files: false,
+            variants: true,
+            alpha: true,
+            blur: true,
+        }
+    }
+
+    fn default_file_name(&self, cfg: &Config, _variant: Option<&Variant>) -> String {
+        format!("{}.json", cfg.meta.slug())
+    }
+
+    fn inputs(&self, target: &config::Target) -> Vec<PathBuf> {
+        target.manifest.iter().map(PathBuf::from).collect()
+    }
+
+    fn render(&self, cfg: &Config, target: &config::Target, root: &Path) -> Result<Vec<Output>> {
+        let dir = Path::new(&target.path);
+        let mut outputs = Vec::new();
+        if let Some(manifest) = &target.manifest {
+            let manifest = Path::new(manifest);
+            check_themes_dir(manifest, dir, root)?;
+            outputs.push(extension_manifest(cfg, manifest, root)?);
+        }
+        let mut themes = vec![];
+        for v in self.variants(cfg) {
+            let palette = self.palette(cfg, target, v);
+            let ui = &palette.ui;
+            let title = self.variant_title(cfg, v);
+            let mut style: Map<String, Value> = style_colors(&palette).into_iter().map(|(k, c)| (k.to_string(), json!(c))).collect();
+            style.insert("background.appearance".into(), json!(appearance(v, ui)));
+            for (name, c) in palette.base.ansi.colors() {
+                style.insert(format!("terminal.ansi.{}", name), json!(c.base));
+                style.insert(format!("terminal.ansi.bright_{}", name), json!(c.bright));
+                style.insert(format!("terminal.ansi.dim_{}", name), json!(c.dim));
+            }
+            for (name, c) in status_colors(&palette) {
+                style.insert(name.to_string(), json!(c));
+                style.insert(format!("{}.background", name), json!(c.apply_alpha(0.1)));
+                style.insert(format!("{}.border", name), json!(c.apply_alpha(0.5)));
+            }
+            style.insert("players".into(), players(&palette));
+            style.insert("syntax".into(), syntax(cfg, &palette));
+            themes.push(json!({ "name": title, "appearance": "dark", "style": style }));
+        }
+        let root_obj = json!({
+            "$schema": "https://zed.dev/schema/themes/v0.2.0.json",
+            "name": cfg.meta.name,
+            "author": cfg.meta.author.clone().unwrap_or_default(),
+            "themes": themes
+        });
+        let path = dir.join(self.file_name(cfg, target, None));
+        schema::ZED_THEME.validate(&root_obj).with_context(|| format!("validating {}", path.display()))?;
+        outputs.insert(0, Output::new(path, serde_json::to_string_pretty(&root_obj)?));
+        Ok(outputs)
+    }
+}
+
+/// Zed loads an extension's themes from `themes/` next to `extension.toml`;
+/// fail unless that is (or links to) the directory this target writes.
+fn check_themes_dir(manifest: &Path, dir: &Path, root: &Path) -> Result<()> {
+    let ext_root = manifest.parent().unwrap_or(Path::new(""));
+    let themes = ext_root.join("themes");
+    if normalize(&themes) == normalize(dir) {
+        return Ok(());
+    }
+    let target = dir.strip_prefix(ext_root).unwrap_or(dir);
+    let fix = format!("fix with `rm -f {0} && ln -s {1} {0} && git add {0}`", themes.display(), target.display());
+    match fs::read_link(root.join(&themes)) {
+        Ok(link) if normalize(&ext_root.join(&link)) == normalize(dir) => Ok(()),
+        Ok(link) => bail!("{} links to {}, but the zed target writes to {}; {}", themes.display(), link.display(), dir.display(), fix),
+        Err(_) => bail!("{} must be a symlink to {} so Zed finds the generated themes; {}", themes.display(), dir.display(), fix),
+    }
+}
+
+/// Resolve `.` and `..` components without touching the filesystem, so
+/// `./zed/themes` and `zed/themes` compare equal.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if matches!(out.components().next_back(), Some(Component::Normal(_))) => { out.pop(); }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// `extension.toml` with name, version, authors and description taken from
+/// `[meta]`. Everything else, comments included, is left as written; `id` and
+/// `schema_version` are only filled in when missing.
+fn extension_manifest(cfg: &Config, path: &Path, root: &Path) -> Result<Output> {
+    let text = match fs::read_to_string(root.join(path)) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+    };
+    let contents = update_manifest(cfg, &text).with_context(|| format!("updating {}", path.display()))?;
+    Ok(Output::new(path, contents))
+}
+
+fn update_manifest(cfg: &Config, text: &str) -> Result<String> {
+    let mut doc: toml_edit::DocumentMut = text.parse()?;
+    let meta = &cfg.meta;
+    match doc.get("id") {
+        None => set(&mut doc, "id", meta.slug()),
+        Some(id) if id.as_str() == Some(meta.slug().as_str()) => {}
+        // Zed keys installs by id, so a renamed theme must not silently become a new extension.
+        Some(id) => bail!("`id` is {} but `[meta].name` gives {:?}; rename one to match", id.to_string().trim(), meta.slug()),
+    }
+    set(&mut doc, "name", meta.name.as_str());
+    if let Some(v) = &meta.version { set(&mut doc, "version", v.as_str()); }
+    if !doc.contains_key("schema_version") { set(&mut doc, "schema_version", 1); }
+    if let Some(a) = &meta.author { set(&mut doc, "authors", toml_edit::Array::from_iter([a.as_str()])); }
+    if let Some(d) = &meta.description { set(&mut doc, "description", d.as_str()); }
+    Ok(doc.to_string())
+}
+
+/// Assign a top-level key, keeping an existing value's surrounding whitespace and comment.
+fn set(doc: &mut toml_edit::DocumentMut, key: &str, value: impl Into<toml_edit::Value>) {
+    let mut value = value.into();
+    if let Some(old) = doc.get(key).and_then(|item| item.as_value()) { *value.decor_mut() = old.decor().clone(); }
+    doc[key] = toml_edit::Item::Value(value);
+}
+
+/// `opaque` unless the variant's background is translucent, in which case
+/// Zed blurs what is behind the window when the variant sets a blur radius.
+fn appearance(v: &Variant, ui: &UiPalette) -> &'static str {
+    match (ui.background.is_opaque(), v.blur_radius.unwrap_or(0)) {
+        (true, _) => "opaque",
+        (false, 0) => "transparent",
+        (false, _) => "blurred",
+    }
+}
+
+/// Theme `style` colors, derived from the variant's UI palette, the borders
+/// and the ANSI accents. On translucent variants the code pane is a shade
+/// darker than the window and the gutter uses the elevated surface, so both
+/// stay distinct from whatever shows through.
+fn style_colors(palette: &Palette) -> Vec<(&'static str, Color)> {
+    let (ui, b, a) = (&palette.ui, &palette.border, &palette.base.ansi);
+    let (yellow, blue, cyan) = (a.yellow.base, a.blue.base, a.cyan.base);
+    let (pane, gutter) = if ui.background.is_opaque() {
+        (ui.background, ui.background)
+    } else {
+        (ui.background.darken(0.03), Color { a: ui.background.a, ..ui.background_elevated })
+    };
+    let transparent = Color::rgba(0, 0, 0, 0);
+    vec![
+        // Borders
+        ("border", b.border),
+        ("border.variant", b.border_variant),
+        ("border.focused", b.border_focused),
+        ("border.selected", b.border_selected),
+        ("border.transparent", transparent),
+        ("border.disabled", b.border_variant),
+        // Surfaces and elements
+        ("background", ui.background),
+        ("surface.background", ui.background_alt),
+        ("elevated_surface.background", ui.background_elevated),
+        ("element.background", ui.background_elevated),
+        ("element.hover", ui.line_highlight),
+        ("element.active", ui.selection),
+        ("element.selected", ui.selection),
+        ("element.disabled", ui.background_alt),
+        ("drop_target.background", ui.selection.apply_alpha(0.5)),
+        ("ghost_element.background", transparent),
+        ("ghost_element.hover", ui.line_highlight),
+        ("ghost_element.active", ui.selection),
+        ("ghost_element.selected", ui.selection),
+        ("ghost_element.disabled", transparent),
+        // Text and icons
+        ("text", ui.foreground),
+        ("text.muted", ui.foreground_muted),
+        ("text.placeholder", ui.foreground_dim),
+        ("text.disabled", ui.foreground_dim),
+        ("text.accent", cyan),
+        ("icon", ui.foreground),
+        ("icon.muted", ui.foreground_muted),
+        ("icon.disabled", ui.foreground_dim),
+        ("icon.placeholder", ui.foreground_dim),
+        ("icon.accent", cyan),
+        ("link_text.hover", blue),
+        // Title, status and tool bars
+        ("title_bar.background", ui.background_alt),
+        ("title_bar.inactive_background", ui.background),
+        ("status_bar.background", ui.background_alt),
+        ("toolbar.background", pane),
+        // Tabs
+        ("tab_bar.background", ui.background_alt),
+        ("tab.active_background", pane),
+        ("tab.inactive_background", ui.background_alt),
+        // Panels and panes
+        ("panel.background", ui.background_alt),
+        ("panel.focused_border", b.border_focused),
+        ("panel.indent_guide", b.border_variant),
+        ("panel.indent_guide_active", b.border),
+        ("panel.indent_guide_hover", b.border_focused),
+        ("pane.focused_border", b.border_focused),
+        ("pane_group.border", b.border_variant),
+        // Scrollbar
+        ("scrollbar.thumb.background", b.border.apply_alpha(0.4)),
+        ("scrollbar.thumb.hover_background", b.border.apply_alpha(0.6)),
+        ("scrollbar.thumb.border", transparent),
+        ("scrollbar.track.background", transparent),
+        ("scrollbar.track.border", b.border_variant.apply_alpha(0.5)),
+        // Search
+        ("search.match_background", yellow.apply_alpha(0.25)),
+        // Editor
+        ("editor.background", pane),
+        ("editor.foreground", ui.foreground),
+        ("editor.gutter.background", gutter),
+        ("editor.subheader.background", ui.background_alt),
+        ("editor.active_line.background", ui.line_highlight),
+        ("editor.highlighted_line.background", ui.line_highlight),
+        ("editor.line_number", ui.foreground_dim),
+        ("editor.active_line_number", ui.foreground),
+        ("editor.invisible", b.border),
+        ("editor.wrap_guide", b.border_variant),
+        ("editor.active_wrap_guide", b.border),
+        ("editor.indent_guide", b.border_variant),
+        ("editor.indent_guide_active", b.border),
+        ("editor.document_highlight.read_background", ui.selection.apply_alpha(0.4)),
+        ("editor.document_highlight.write_background", ui.selection.apply_alpha(0.6)),
+        ("editor.document_highlight.bracket_background", cyan.apply_alpha(0.2)),
+        // Terminal
+        ("terminal.background", ui.background),
+        ("terminal.foreground", ui.foreground),
+        ("terminal.bright_foreground", a.white.bright),
+        ("terminal.dim_foreground", ui.foreground_dim),
+        // Version control gutter markers
+        ("version_control.added", a.green.base),
+        ("version_control.modified", blue),
+        ("version_control.deleted", a.red.base),
+        ("version_control.conflict", a.magenta.base),
+        ("version_control.renamed", cyan),
+        ("version_control.ignored", ui.foreground_dim),
+    ]
+}
+
+/// Diagnostic and file-status colors. Each also gets a faint `.background`
+/// and a half-strength `.border`.
+fn status_colors(palette: &Palette) -> Vec<(&'static str, Color)> {
+    let (ui, a) = (&palette.ui, &palette.base.ansi);
+    vec![
+        ("error", a.red.base),
+        ("warning", a.yellow.base),
+        ("info", a.blue.base),
+        ("hint", a.cyan.base),
+        ("success", a.green.base),
+        ("created", a.green.base),
+        ("modified", a.blue.base),
+        ("deleted", a.red.base),
+        ("conflict", a.magenta.base),
+        ("renamed", a.cyan.base),
+        ("hidden", ui.foreground_dim),
+        ("ignored", ui.foreground_dim),
+        ("predictive", ui.foreground_dim),
+        ("unreachable", ui.foreground_dim),
+    ]
+}
+
+/// Collaborator colors. The first entry is the local user and follows the
+/// palette's cursor and selection; the rest cycle through the ANSI accents.
+fn players(palette: &Palette) -> Value {
+    let (ui, a) = (&palette.ui, &palette.base.ansi);
+    let local = json!({ "cursor": ui.cursor, "background": ui.cursor, "selection": ui.selection });
+    let others = [a.blue.base, a.magenta.base, a.yellow.base, a.green.base, a.red.base, a.cyan.base, palette.syntax.lavender]
+        .into_iter()
+        .map(|c| json!({ "cursor": c, "background": c, "selection": c.apply_alpha(0.25) }));
+    Value::Array(std::iter::once(local).chain(others).collect())
+}
+
+/// Zed highlight names for each role. Zed matches the longest dotted prefix,
+/// so `function` also covers `function.method` unless it is set separately.
+fn highlights(role: Role) -> &'static [&'static str] {
+    match role {
+        Role::Comment => &["comment", "comment.doc"],
+        Role::Keyword => &["keyword"],
+        Role::Function => &["function"],
+        Role::Method => &["function.method"],
+        Role::Type => &["type", "enum"],
+        Role::Constructor => &["constructor", "variant"],
+        Role::String => &["string", "string.special", "text.literal"],
+        Role::Escape => &["string.escape", "string.special.symbol"],
+        Role::Regex => &["string.regex"],
+        Role::Number => &["number"],
+        Role::Boolean => &["boolean"],
+        Role::Constant => &["constant"],
+        Role::Operator => &["operator"],
+        Role::Attribute => &["attribute"],
+        Role::Variable => &["variable"],
+        Role::Parameter => &["variable.parameter"],
+        Role::Property => &["property"],
+        Role::Macro => &["function.macro", "preproc"],
+        Role::Namespace => &["namespace"],
+        Role::Punctuation => &["punctuation", "punctuation.bracket", "punctuation.delimiter"],
+        Role::Tag => &["tag"],
+    }
+}
+
+fn syntax(cfg: &Config, palette: &Palette) -> Value {
+    let mut out = Map::new();
+    for (role, style) in cfg.syntax.styles(palette) {
+        if style == Style::default() {
+            continue;
+        }
+        let mut entry = Map::new();
+        if let Some(c) = style.color { entry.insert("color".into(), json!(c)); }
+        if style.italic { entry.insert("font_style".into(), json!("italic")); }
+        if style.bold { entry.insert("font_weight".into(), json!(700)); }
+        // Zed has no underline for syntax highlights.
+        for name in highlights(role) {
+            out.insert(name.to_string(), Value::Object(entry.clone()));
+        }
+    }
+    Value::Object(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn themes_dir_accepts_equivalent_paths() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let manifest = Path::new("extension.toml");
+        for dir in ["zed/themes", "./zed/themes", "zed/./themes/", "zed/../zed/themes", "./themes"] {
+            assert!(check_themes_dir(manifest, Path::new(dir), &root).is_ok(), "{} should match the themes link", dir);
+        }
+        assert!(check_themes_dir(manifest, Path::new("zed/other"), &root).is_err());
+    }
+
+    #[test]
+    fn manifest_update_keeps_comments_and_unmanaged_keys() {
+        let cfg = crate::targets::test_config();
+        let text = "# Zed extension\nid = \"subliminal-nightfall\"\nname = \"Old\" # shown in the gallery\nversion = \"0.0.1\"\nschema_version = 1\nrepository = \"https://example.com\"\n";
+        let updated = update_manifest(&cfg, text).unwrap();
+        assert!(updated.starts_with("# Zed extension\nid = \"subliminal-nightfall\"\n"));
+        assert!(updated.contains(&format!("name = {:?} # shown in the gallery\n", cfg.meta.name)));
+        assert!(updated.contains(&format!("version = {:?}\n", cfg.meta.version.as_deref().unwrap())));
+        assert!(updated.contains("repository = \"https://example.com\"\n"));
+        let err = update_manifest(&cfg, "id = \"renamed\"\n").unwrap_err();
+        assert!(err.to_string().contains("`id` is \"renamed\""), "{}", err);
+    }
+}
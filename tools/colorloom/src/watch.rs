@@ -0,0 +1,132 @@
+//! `colorloom watch`: regenerate targets as the config and templates change.
+
+use crate::check;
+use crate::config::Config;
+use crate::targets::Registry;
+use anyhow::Result;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver},
+    time::Duration,
+};
+
+/// Quiet period after the last event before regenerating, so an editor's
+/// write-rename-chmod sequence triggers one rebuild.
+const DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Watch `config_path` and every input file its targets reference, regenerating
+/// on change. A config edit re-renders every target, since any palette entry
+/// can reach any of them; an input edit re-renders only the targets reading
+/// it. Either way only files whose contents changed are written. Errors are
+/// reported and watching continues; only watcher setup failures are returned.
+pub fn run(config_path: &Path, root: &Path, registry: &Registry) -> Result<()> {
+    let config_path = normalize(&root.join(config_path));
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    let mut watched_dirs = HashSet::new();
+    let mut cfg: Option<Config> = None;
+    // `None` means everything is affected: first run, or the config itself changed.
+    let mut changed: Option<HashSet<PathBuf>> = None;
+    // Files the last pass wrote, so events from our own writes (e.g. to a
+    // manifest that is also an input) don't trigger another pass.
+    let mut written = HashMap::new();
+
+    println!("Watching {} (Ctrl-C to stop)", config_path.display());
+    loop {
+        if changed.is_none() {
+            cfg = Config::load(&config_path).map_err(|e| eprintln!("error: {:#}", e)).ok();
+        }
+        if let Some(cfg) = &cfg {
+            written = regenerate(cfg, registry, root, changed.as_ref());
+        }
+
+        // Watch parent directories rather than files: editors commonly save by
+        // writing a temp file and renaming it over the original.
+        let mut files = vec![config_path.clone()];
+        if let Some(cfg) = &cfg {
+            for t in cfg.targets.iter().filter(|t| t.enabled) {
+                files.extend(registry.inputs(t).iter().map(|p| normalize(&root.join(p))));
+            }
+        }
+        for dir in files.iter().filter_map(|f| f.parent()) {
+            if watched_dirs.insert(dir.to_path_buf()) {
+                watcher.watch(dir, RecursiveMode::NonRecursive)?;
+            }
+        }
+
+        let hits = wait_for_changes(&rx, &files, &written)?;
+        changed = if hits.contains(&config_path) { None } else { Some(hits) };
+    }
+}
+
+/// Re-render enabled targets and write only files whose contents changed,
+/// returning them with the contents written. With `changed` set, only targets
+/// reading one of those inputs are rendered.
+fn regenerate(cfg: &Config, registry: &Registry, root: &Path, changed: Option<&HashSet<PathBuf>>) -> HashMap<PathBuf, String> {
+    let mut written = HashMap::new();
+    for t in cfg.targets.iter().filter(|t| t.enabled) {
+        if let Some(changed) = changed {
+            let inputs: Vec<_> = registry.inputs(t).iter().map(|p| normalize(&root.join(p))).collect();
+            if !inputs.iter().any(|p| changed.contains(p)) {
+                continue;
+            }
+        }
+        let outputs = match registry.render(cfg, t, root) {
+            Ok(outputs) => outputs,
+            Err(e) => {
+                eprintln!("error: target {}: {:#}", t.id, e);
+                continue;
+            }
+        };
+        for drift in check::compare(root, &outputs) {
+            match drift.output.write(root) {
+                Ok(()) => {
+                    println!("updated {} ({})", drift.output.path.display(), t.id);
+                    written.insert(normalize(&root.join(&drift.output.path)), drift.output.contents);
+                }
+                Err(e) => eprintln!("error: {:#}", e),
+            }
+        }
+    }
+    if written.is_empty() {
+        println!("no changes");
+    }
+    written
+}
+
+/// Block until at least one of `files` is touched, then keep draining events
+/// until things go quiet for [`DEBOUNCE`]. Files still holding what the last
+/// pass `written` to them don't count.
+fn wait_for_changes(rx: &Receiver<notify::Result<Event>>, files: &[PathBuf], written: &HashMap<PathBuf, String>) -> Result<HashSet<PathBuf>> {
+    let mut hits = HashSet::new();
+    let collect = |res: notify::Result<Event>, hits: &mut HashSet<PathBuf>| match res {
+        Ok(event) if !matches!(event.kind, EventKind::Access(_)) => {
+            hits.extend(event.paths.iter().map(|p| normalize(p)).filter(|p| files.contains(p)));
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("watch error: {}", e),
+    };
+    loop {
+        collect(rx.recv()?, &mut hits);
+        while let Ok(res) = rx.recv_timeout(DEBOUNCE) {
+            collect(res, &mut hits);
+        }
+        hits.retain(|p| written.get(p).is_none_or(|ours| fs::read_to_string(p).ok().as_ref() != Some(ours)));
+        if !hits.is_empty() {
+            return Ok(hits);
+        }
+    }
+}
+
+/// Canonicalize the parent directory so paths from the watcher (which may
+/// resolve symlinks) compare equal to configured ones, even for files that
+/// are mid-rename and don't currently exist.
+fn normalize(path: &Path) -> PathBuf {
+    match (path.parent(), path.file_name()) {
+        (Some(dir), Some(name)) => dir.canonicalize().map(|d| d.join(name)).unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf(),
+    }
+}